@@ -0,0 +1,164 @@
+//! Module for the [`Annulus`] trait
+
+use crate::{Circle, FloatingScalar, FloatingVector2, Scalar as _, Vector2};
+
+/// The scalar type of an [`Annulus`]
+pub type Scalar<T> = <<T as Annulus>::Vector as Vector2>::Scalar;
+
+/**
+Trait for manipulating annuli
+
+An annulus is the ring-shaped region between two concentric circles.
+
+It is implemented for any pair of a [`Circle`] and its scalar type,
+where the circle is the outer circle and the scalar is the inner radius.
+```
+use vector2math::*;
+
+let ring = f64::Annul::new([0.0, 0.0], 1.0, 2.0);
+assert!(ring.contains([1.5, 0.0]));
+assert!(!ring.contains([0.5, 0.0]));
+assert_eq!(3.0 * f64::PI, ring.area());
+```
+*/
+pub trait Annulus: Copy
+where
+    Scalar<Self>: FloatingScalar,
+{
+    /// The circle type
+    type Circle: Circle<Vector = Self::Vector>;
+    /// The vector type
+    type Vector: FloatingVector2;
+    /// Create a new annulus from its outer circle and an inner radius
+    fn from_circle(outer: Self::Circle, inner_radius: Scalar<Self>) -> Self;
+    /// Get the outer circle
+    fn outer(self) -> Self::Circle;
+    /// Get the inner radius
+    fn inner_radius(self) -> Scalar<Self>;
+    /// Create a new annulus from a center and inner and outer radii
+    fn new(center: Self::Vector, inner_radius: Scalar<Self>, outer_radius: Scalar<Self>) -> Self {
+        Self::from_circle(Self::Circle::new(center, outer_radius), inner_radius)
+    }
+    /// Get the center
+    fn center(self) -> Self::Vector {
+        self.outer().center()
+    }
+    /// Get the outer radius
+    fn outer_radius(self) -> Scalar<Self> {
+        self.outer().radius()
+    }
+    /// Get the inner circle
+    fn inner(self) -> Self::Circle {
+        self.outer().with_radius(self.inner_radius())
+    }
+    /// Get the difference between the outer and inner radii
+    fn thickness(self) -> Scalar<Self> {
+        (self.outer_radius().abs() - self.inner_radius().abs()).abs()
+    }
+    /// Transform the annulus into one with a different outer circle
+    fn with_outer(self, outer: Self::Circle) -> Self {
+        Self::from_circle(outer, self.inner_radius())
+    }
+    /// Transform the annulus into one with a different inner radius
+    fn with_inner_radius(self, inner_radius: Scalar<Self>) -> Self {
+        Self::from_circle(self.outer(), inner_radius)
+    }
+    /// Get the annulus that is this one translated by some vector
+    fn translated(self, offset: Self::Vector) -> Self {
+        self.with_outer(self.outer().translated(offset))
+    }
+    /// Get the area
+    fn area(self) -> Scalar<Self> {
+        (self.outer().area() - self.inner().area()).abs()
+    }
+    /// Get the total length of the inner and outer boundaries
+    fn perimeter(self) -> Scalar<Self> {
+        self.outer().circumference().abs() + self.inner().circumference().abs()
+    }
+    /// Check that the annulus contains the given point. Includes edges.
+    fn contains(self, point: Self::Vector) -> bool {
+        let dist = self.center().dist(point);
+        let (outer, inner) = (self.outer_radius().abs(), self.inner_radius().abs());
+        outer.minn(inner) <= dist && dist <= outer.maxx(inner)
+    }
+    /// Check that the annulus contains all points
+    fn contains_all<I>(self, points: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        points.into_iter().all(|point| self.contains(point))
+    }
+    /// Check that the annulus contains any point
+    fn contains_any<I>(self, points: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        points.into_iter().any(|point| self.contains(point))
+    }
+    /// Get the smallest square that the annulus fits inside
+    fn to_square(self) -> [Scalar<Self>; 4] {
+        self.outer().to_square()
+    }
+    /// Get points along the annulus's outer and inner boundaries
+    ///
+    /// The outer ring goes in the positive angle direction and the inner ring
+    /// goes the opposite way, so together they form a polygon with a hole.
+    /// The inner ring is empty if the inner radius is zero. The points are
+    /// spaced so that the rings deviate from the circles by no more than `tolerance`.
+    fn flatten(self, tolerance: Scalar<Self>) -> [Vec<Self::Vector>; 2] {
+        let (outer, inner) = (self.outer_radius().abs(), self.inner_radius().abs());
+        let ring = |radius| Self::Circle::new(self.center(), radius).outline(tolerance);
+        let mut hole = if outer.minn(inner).is_zero() {
+            Vec::new()
+        } else {
+            ring(outer.minn(inner))
+        };
+        hole.reverse();
+        [ring(outer.maxx(inner)), hole]
+    }
+}
+
+impl<C, S> Annulus for (C, S)
+where
+    C: Circle,
+    C::Vector: FloatingVector2<Scalar = S>,
+    S: FloatingScalar,
+{
+    type Circle = C;
+    type Vector = C::Vector;
+    fn from_circle(outer: Self::Circle, inner_radius: Scalar<Self>) -> Self {
+        (outer, inner_radius)
+    }
+    fn outer(self) -> Self::Circle {
+        self.0
+    }
+    fn inner_radius(self) -> Scalar<Self> {
+        self.1
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn annulus() {
+    use crate::{f64, Polygon};
+    let ring = f64::Annul::new([1.0, 1.0], 1.0, 2.0);
+    // Both boundaries are included
+    assert!(ring.contains([2.0, 1.0]));
+    assert!(ring.contains([3.0, 1.0]));
+    assert!(ring.contains([1.0, -0.5]));
+    assert!(!ring.contains([1.5, 1.0]));
+    assert!(!ring.contains([3.01, 1.0]));
+    // Swapped radii describe the same annulus
+    assert!(f64::Annul::new([1.0, 1.0], 2.0, 1.0).contains([2.0, 1.0]));
+    let [outer, inner] = ring.flatten(0.01);
+    assert!(outer
+        .iter()
+        .all(|p| (p.dist([1.0, 1.0]) - 2.0).abs() < 1e-12));
+    assert!(inner
+        .iter()
+        .all(|p| (p.dist([1.0, 1.0]) - 1.0).abs() < 1e-12));
+    let winding = |p| outer.winding_number(p) + inner.winding_number(p);
+    assert_eq!(1, winding([2.5, 1.0]));
+    assert_eq!(0, winding([1.0, 1.0]));
+    assert!(f64::Annul::new([0.0, 0.0], 0.0, 1.0).flatten(0.1)[1].is_empty());
+}
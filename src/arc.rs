@@ -0,0 +1,213 @@
+//! Module for the [`Arc`] trait
+
+use crate::{
+    circle::arc_points, Circle, FloatingScalar, FloatingVector2, Rectangle, Scalar as _, Vector2,
+};
+
+/// The scalar type of an [`Arc`]
+pub type Scalar<T> = <<T as Arc>::Vector as Vector2>::Scalar;
+
+/**
+Trait for manipulating circular arcs
+
+An arc is defined by a [`Circle`], a start angle in radians, and a sweep in radians.
+A positive sweep goes in the positive angle direction from the start angle.
+
+It is implemented for any triple of a [`Circle`] and two of its scalar type.
+```
+use vector2math::*;
+
+let arc = f64::Arc::new([0.0, 0.0], 2.0, 0.0, f64::PI / 2.0);
+assert_eq!(f64::PI, arc.length());
+assert_eq!([2.0, 0.0], arc.start_point());
+assert!(arc.end_point().dist([0.0, 2.0]) < 1e-12);
+assert!(arc.bounding_rect::<f64::Rect>().size().dist([2.0, 2.0]) < 1e-12);
+```
+*/
+pub trait Arc: Copy
+where
+    Scalar<Self>: FloatingScalar,
+{
+    /// The circle type
+    type Circle: Circle<Vector = Self::Vector>;
+    /// The vector type
+    type Vector: FloatingVector2;
+    /// Create a new arc from a circle, a start angle, and a sweep
+    fn from_circle(circle: Self::Circle, start: Scalar<Self>, sweep: Scalar<Self>) -> Self;
+    /// Get the circle the arc is a part of
+    fn circle(self) -> Self::Circle;
+    /// Get the start angle in radians
+    fn start_angle(self) -> Scalar<Self>;
+    /// Get the sweep in radians
+    fn sweep(self) -> Scalar<Self>;
+    /// Create a new arc from a center, a radius, a start angle, and a sweep
+    fn new(
+        center: Self::Vector,
+        radius: Scalar<Self>,
+        start: Scalar<Self>,
+        sweep: Scalar<Self>,
+    ) -> Self {
+        Self::from_circle(Self::Circle::new(center, radius), start, sweep)
+    }
+    /// Get the center of the arc's circle
+    fn center(self) -> Self::Vector {
+        self.circle().center()
+    }
+    /// Get the radius of the arc's circle
+    fn radius(self) -> Scalar<Self> {
+        self.circle().radius()
+    }
+    /// Get the end angle in radians
+    fn end_angle(self) -> Scalar<Self> {
+        self.start_angle() + self.sweep()
+    }
+    /// Transform the arc into one with a different circle
+    fn with_circle(self, circle: Self::Circle) -> Self {
+        Self::from_circle(circle, self.start_angle(), self.sweep())
+    }
+    /// Transform the arc into one with different angles
+    fn with_angles(self, start: Scalar<Self>, sweep: Scalar<Self>) -> Self {
+        Self::from_circle(self.circle(), start, sweep)
+    }
+    /// Get the arc that is this one translated by some vector
+    fn translated(self, offset: Self::Vector) -> Self {
+        self.with_circle(self.circle().translated(offset))
+    }
+    /// Get the arc that goes the opposite direction
+    fn reversed(self) -> Self {
+        self.with_angles(self.end_angle(), -self.sweep())
+    }
+    /// Get the length of the arc
+    fn length(self) -> Scalar<Self> {
+        self.radius().abs() * self.sweep().abs()
+    }
+    /// Get the point on the arc at the given angle
+    fn point_at_angle(self, radians: Scalar<Self>) -> Self::Vector {
+        self.center()
+            .add(Self::Vector::from_angle(radians).mul(self.radius()))
+    }
+    /// Get the point on the arc at some fraction `t` of the way from its start to its end
+    fn point_at(self, t: Scalar<Self>) -> Self::Vector {
+        self.point_at_angle(self.start_angle() + self.sweep() * t)
+    }
+    /// Get the start point
+    fn start_point(self) -> Self::Vector {
+        self.point_at_angle(self.start_angle())
+    }
+    /// Get the end point
+    fn end_point(self) -> Self::Vector {
+        self.point_at_angle(self.end_angle())
+    }
+    /// Get the point halfway along the arc
+    fn midpoint(self) -> Self::Vector {
+        self.point_at(Scalar::<Self>::ONE / Scalar::<Self>::TWO)
+    }
+    /// Check if the arc passes through the given angle in radians
+    fn contains_angle(self, radians: Scalar<Self>) -> bool {
+        let sweep = self.sweep();
+        if sweep.abs() >= Scalar::<Self>::TAU {
+            return true;
+        }
+        let offset = if sweep >= Scalar::<Self>::ZERO {
+            radians - self.start_angle()
+        } else {
+            self.start_angle() - radians
+        };
+        wrap_angle(offset) <= sweep.abs()
+    }
+    /// Get the smallest axis-aligned rectangle that contains the arc
+    fn bounding_rect<R>(self) -> R
+    where
+        R: Rectangle<Vector = Self::Vector>,
+    {
+        let mut min = self.start_point().min2(self.end_point());
+        let mut max = self.start_point().max2(self.end_point());
+        let mut extreme = Scalar::<Self>::ZERO;
+        let quarter = Scalar::<Self>::PI / Scalar::<Self>::TWO;
+        for _ in 0..4 {
+            if self.contains_angle(extreme) {
+                let point = self.point_at_angle(extreme);
+                min = min.min2(point);
+                max = max.max2(point);
+            }
+            extreme = extreme + quarter;
+        }
+        R::new(min, max.sub(min))
+    }
+    /// Get the point on the arc closest to the given point
+    fn closest_point(self, point: Self::Vector) -> Self::Vector {
+        let offset = point.sub(self.center());
+        if !offset.squared_mag().is_zero() && self.contains_angle(offset.atan()) {
+            return self.point_at_angle(offset.atan());
+        }
+        let (start, end) = (self.start_point(), self.end_point());
+        if point.squared_dist(start) <= point.squared_dist(end) {
+            start
+        } else {
+            end
+        }
+    }
+    /// Get points along the arc, including both endpoints
+    ///
+    /// The points are spaced so that the polyline they form deviates
+    /// from the arc by no more than `tolerance`.
+    fn flatten(self, tolerance: Scalar<Self>) -> Vec<Self::Vector> {
+        arc_points(
+            self.center(),
+            self.radius(),
+            self.start_angle(),
+            self.sweep(),
+            tolerance,
+        )
+    }
+}
+
+impl<C, S> Arc for (C, S, S)
+where
+    C: Circle,
+    C::Vector: FloatingVector2<Scalar = S>,
+    S: FloatingScalar,
+{
+    type Circle = C;
+    type Vector = C::Vector;
+    fn from_circle(circle: Self::Circle, start: Scalar<Self>, sweep: Scalar<Self>) -> Self {
+        (circle, start, sweep)
+    }
+    fn circle(self) -> Self::Circle {
+        self.0
+    }
+    fn start_angle(self) -> Scalar<Self> {
+        self.1
+    }
+    fn sweep(self) -> Scalar<Self> {
+        self.2
+    }
+}
+
+/// Wrap an angle in radians into the range `[0, 2π)`
+pub(crate) fn wrap_angle<S>(radians: S) -> S
+where
+    S: FloatingScalar,
+{
+    radians - (radians / S::TAU).floor() * S::TAU
+}
+
+#[cfg(test)]
+#[test]
+fn arc() {
+    use crate::f64;
+    let arc = f64::Arc::new([1.0, 1.0], 2.0, 0.5, 2.0);
+    let bounds: f64::Rect = arc.bounding_rect();
+    assert!((bounds.bottom() - 3.0).abs() < 1e-12);
+    assert!((bounds.top() - arc.start_point().y()).abs() < 1e-12);
+    assert!((bounds.right() - arc.start_point().x()).abs() < 1e-12);
+    assert!((bounds.left() - arc.end_point().x()).abs() < 1e-12);
+    let reversed = arc.reversed();
+    assert!(reversed.contains_angle(1.0));
+    assert!(!reversed.contains_angle(-1.0));
+    assert_eq!(arc.start_point(), arc.closest_point([5.0, 0.0]));
+    assert!(arc.closest_point([1.0, 5.0]).dist([1.0, 3.0]) < 1e-12);
+    let flat = arc.flatten(0.001);
+    assert_eq!(arc.start_point(), flat[0]);
+    assert!(flat.last().unwrap().dist(arc.end_point()) < 1e-12);
+}
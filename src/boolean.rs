@@ -0,0 +1,663 @@
+//! Module for boolean operations on polygons
+
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use crate::{
+    arc::wrap_angle, hull::orientation, FillRule, FloatingScalar, FloatingVector2, Polygon,
+    Scalar as _, Vector2,
+};
+
+/// A way of combining two shapes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BooleanOp {
+    /// The area covered by either shape
+    Union,
+    /// The area covered by both shapes
+    Intersection,
+    /// The area covered by the subject but not the clip
+    Difference,
+    /// The area covered by exactly one of the shapes
+    Xor,
+}
+
+impl BooleanOp {
+    /// Check if a point is in the result given whether it is in the subject and the clip
+    pub fn apply(self, in_subject: bool, in_clip: bool) -> bool {
+        match self {
+            BooleanOp::Union => in_subject || in_clip,
+            BooleanOp::Intersection => in_subject && in_clip,
+            BooleanOp::Difference => in_subject && !in_clip,
+            BooleanOp::Xor => in_subject != in_clip,
+        }
+    }
+}
+
+/**
+Combine two shapes made of polygonal rings
+
+Each shape is a list of rings that are filled with the
+[`FillRule::EvenOdd`](crate::FillRule::EvenOdd) fill rule, so
+holes may wind either way. Rings may overlap each other and themselves.
+
+The result is a list of polygons, each of which is a list of rings.
+The first ring of each polygon is its outer boundary, which is
+[`Winding::Clockwise`](crate::Winding::Clockwise). Any other rings
+are holes, which are counter-clockwise. Each ring starts from its
+point with the lowest x and y.
+
+This is a sweep line algorithm in the style of Martinez–Rueda. One sweep
+splits the edges where they cross or touch, and a second classifies each
+piece by the winding numbers on either side of it, so for `n` edges that
+cross `k` times, this takes about `O((n + k) log n)` time.
+```
+use vector2math::*;
+
+let a = [[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]];
+let b = [[1.0, 1.0], [3.0, 1.0], [3.0, 3.0], [1.0, 3.0]];
+let union = boolean(&[a], &[b], BooleanOp::Union);
+assert_eq!(1, union.len());
+assert_eq!(8, union[0][0].len());
+
+let overlap = boolean(&[a], &[b], BooleanOp::Intersection);
+assert_eq!(vec![vec![vec![[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 2.0]]]], overlap);
+
+// Cutting a hole
+let frame = boolean(&[[[-1.0, -1.0], [4.0, -1.0], [4.0, 4.0], [-1.0, 4.0]]], &[a], BooleanOp::Difference);
+assert_eq!(2, frame[0].len());
+```
+*/
+pub fn boolean<V, R, S>(subject: &[R], clip: &[S], op: BooleanOp) -> Vec<Vec<Vec<V>>>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+    R: AsRef<[V]>,
+    S: AsRef<[V]>,
+{
+    combine(&ring_edges(subject), &ring_edges(clip), op, |winding| {
+        FillRule::EvenOdd.is_inside(winding)
+    })
+}
+
+/// Get the edges of a list of rings, skipping degenerate rings and edges
+pub(crate) fn ring_edges<V, R>(rings: &[R]) -> Vec<(V, V)>
+where
+    V: Vector2,
+    R: AsRef<[V]>,
+{
+    rings
+        .iter()
+        .map(AsRef::as_ref)
+        .filter(|ring| ring.len() >= 3)
+        .flat_map(|ring| (0..ring.len()).map(move |i| (ring[i], ring[(i + 1) % ring.len()])))
+        .filter(|(a, b)| a.x() != b.x() || a.y() != b.y())
+        .collect()
+}
+
+/// Combine two shapes given as edges, where `fill` decides
+/// which winding numbers are inside each shape
+pub(crate) fn combine<V, F>(
+    subject: &[(V, V)],
+    clip: &[(V, V)],
+    op: BooleanOp,
+    fill: F,
+) -> Vec<Vec<Vec<V>>>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+    F: Fn(i32) -> bool,
+{
+    let all: Vec<(V, V)> = subject.iter().chain(clip).copied().collect();
+    let (points, pieces) = split_edges(&all, subject.len());
+    let kept = classify(&points, &pieces, |[subject, clip]| {
+        op.apply(fill(subject), fill(clip))
+    });
+    let rings = trace_rings(&points, &kept);
+    // Assign each hole to the smallest outer ring that contains it
+    let (mut outers, mut holes) = (Vec::new(), Vec::new());
+    for ring in rings {
+        let area = ring.signed_area();
+        if area > V::Scalar::ZERO {
+            outers.push((area, vec![ring]));
+        } else if area < V::Scalar::ZERO {
+            holes.push(ring);
+        }
+    }
+    outers.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+    for hole in holes {
+        let owner = outers
+            .iter_mut()
+            .find(|(_, polygon)| polygon[0].contains_all(hole.iter().copied(), FillRule::NonZero));
+        if let Some((_, polygon)) = owner {
+            polygon.push(hole);
+        }
+    }
+    outers.into_iter().map(|(_, polygon)| polygon).collect()
+}
+
+/// A piece of an edge between two of the split points, from the lesser to the greater
+#[derive(Debug, Clone, Copy)]
+struct Piece {
+    start: usize,
+    end: usize,
+    /// How much the subject's and the clip's winding numbers increase
+    /// when crossing the piece from its right to its left
+    winding: [i32; 2],
+}
+
+/// Part of an edge that crosses the sweep line, from its lesser point to its greater one
+#[derive(Debug, Clone, Copy)]
+struct Segment<V> {
+    left: V,
+    right: V,
+    winding: [i32; 2],
+}
+
+/// A segment starting or ending where the sweep line reaches a point
+struct Event<V> {
+    point: V,
+    start: bool,
+    segment: usize,
+}
+
+impl<V: Vector2> PartialEq for Event<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<V: Vector2> Eq for Event<V> {}
+
+impl<V: Vector2> PartialOrd for Event<V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<V: Vector2> Ord for Event<V> {
+    /// Events are reversed so that the heap pops the first one, and segments end before others start
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(&other.point, &self.point)
+            .then(other.start.cmp(&self.start))
+            .then(other.segment.cmp(&self.segment))
+    }
+}
+
+/// Order points along the sweep, by x and then by y
+fn compare<V>(a: &V, b: &V) -> Ordering
+where
+    V: Vector2,
+{
+    a.x()
+        .partial_cmp(&b.x())
+        .unwrap_or(Ordering::Equal)
+        .then(a.y().partial_cmp(&b.y()).unwrap_or(Ordering::Equal))
+}
+
+/// Check if the segment `a` is below the segment `b` where they both cross the sweep line
+///
+/// The segments must not cross each other.
+fn below<V>(a: (V, V), b: (V, V)) -> bool
+where
+    V: Vector2,
+{
+    let ((a_left, a_right), (b_left, b_right)) = (a, b);
+    match compare(&a_left, &b_left) {
+        Ordering::Equal => orientation(a_left, a_right, b_right) == Ordering::Greater,
+        Ordering::Less => match orientation(a_left, a_right, b_left) {
+            Ordering::Equal => orientation(a_left, a_right, b_right) == Ordering::Greater,
+            side => side == Ordering::Greater,
+        },
+        Ordering::Greater => match orientation(b_left, b_right, a_left) {
+            Ordering::Equal => orientation(b_left, b_right, a_right) == Ordering::Less,
+            side => side == Ordering::Less,
+        },
+    }
+}
+
+/// Split edges where they cross or touch each other by sweeping a line across them
+///
+/// The first `subject` edges belong to the subject and the rest to the clip.
+/// Returns the unique points, ordered by x and then y, and the unique pieces between them.
+fn split_edges<V>(edges: &[(V, V)], subject: usize) -> (Vec<V>, Vec<Piece>)
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let mut segments = Vec::with_capacity(edges.len());
+    let mut events = BinaryHeap::with_capacity(edges.len() * 2);
+    for (i, &(a, b)) in edges.iter().enumerate() {
+        let (left, right, winding) = match compare(&a, &b) {
+            Ordering::Less => (a, b, 1),
+            Ordering::Greater => (b, a, -1),
+            Ordering::Equal => continue,
+        };
+        let winding = if i < subject {
+            [winding, 0]
+        } else {
+            [0, winding]
+        };
+        add_segment(
+            &mut segments,
+            &mut events,
+            Segment {
+                left,
+                right,
+                winding,
+            },
+        );
+    }
+    // Segments that are next to each other on the sweep line are split where they meet,
+    // so the segments on the sweep line never cross
+    let mut active: Vec<usize> = Vec::new();
+    while let Some(Event {
+        point,
+        start,
+        segment,
+    }) = events.pop()
+    {
+        let key = |i: usize| (segments[i].left, segments[i].right);
+        if start {
+            let i = active.partition_point(|&other| below(key(other), key(segment)));
+            active.insert(i, segment);
+            if i + 1 < active.len() {
+                intersect(&mut segments, &mut events, segment, active[i + 1]);
+            }
+            if i > 0 {
+                intersect(&mut segments, &mut events, active[i - 1], segment);
+            }
+            merge(&mut segments, &mut active, i);
+            if i > 0 {
+                merge(&mut segments, &mut active, i - 1);
+            }
+        } else if compare(&segments[segment].right, &point) == Ordering::Equal {
+            // Ends of segments that have since been split are skipped
+            if let Some(i) = active.iter().position(|&other| other == segment) {
+                active.remove(i);
+                if i > 0 && i < active.len() {
+                    intersect(&mut segments, &mut events, active[i - 1], active[i]);
+                    merge(&mut segments, &mut active, i - 1);
+                }
+            }
+        }
+    }
+    let mut points: Vec<V> = segments
+        .iter()
+        .flat_map(|segment| vec![segment.left, segment.right])
+        .collect();
+    points.sort_by(compare);
+    points.dedup_by(|a, b| compare(a, b) == Ordering::Equal);
+    // Merge points that rounding has left a tiny distance apart
+    let size = points.iter().fold(V::Scalar::ZERO, |size, p| {
+        size.maxx(p.x().abs()).maxx(p.y().abs())
+    });
+    let tolerance = size * V::Scalar::EPSILON * V::Scalar::from_usize(16);
+    let mut merged: Vec<usize> = (0..points.len()).collect();
+    for i in 0..points.len() {
+        let near = (0..i)
+            .rev()
+            .take_while(|&j| points[i].x() - points[j].x() <= tolerance)
+            .find(|&j| (points[i].y() - points[j].y()).abs() <= tolerance);
+        if let Some(j) = near {
+            merged[i] = merged[j];
+        }
+    }
+    let index = |p: &V| {
+        let i = points
+            .binary_search_by(|q| compare(q, p))
+            .expect("every endpoint is one of the points");
+        merged[i]
+    };
+    let mut pieces: Vec<Piece> = segments
+        .iter()
+        .filter_map(|segment| {
+            let (start, end) = (index(&segment.left), index(&segment.right));
+            let [subject, clip] = segment.winding;
+            // Merging points can turn a nearly vertical piece around
+            match start.cmp(&end) {
+                Ordering::Less => Some(Piece {
+                    start,
+                    end,
+                    winding: [subject, clip],
+                }),
+                Ordering::Greater => Some(Piece {
+                    start: end,
+                    end: start,
+                    winding: [-subject, -clip],
+                }),
+                Ordering::Equal => None,
+            }
+        })
+        .collect();
+    // Combine the windings of edges that overlap
+    pieces.sort_by_key(|piece| (piece.start, piece.end));
+    pieces.dedup_by(|piece, kept| {
+        let same = (piece.start, piece.end) == (kept.start, kept.end);
+        if same {
+            kept.winding[0] += piece.winding[0];
+            kept.winding[1] += piece.winding[1];
+        }
+        same
+    });
+    // Merged segments and edges that cancel out are not part of any boundary
+    pieces.retain(|piece| piece.winding != [0, 0]);
+    (points, pieces)
+}
+
+/// Add a segment and the events where it starts and ends
+fn add_segment<V>(
+    segments: &mut Vec<Segment<V>>,
+    events: &mut BinaryHeap<Event<V>>,
+    segment: Segment<V>,
+) where
+    V: Vector2,
+{
+    let index = segments.len();
+    segments.push(segment);
+    events.push(Event {
+        point: segment.left,
+        start: true,
+        segment: index,
+    });
+    events.push(Event {
+        point: segment.right,
+        start: false,
+        segment: index,
+    });
+}
+
+/// Merge the segment at `i` on the sweep line with the one above it if they are the same,
+/// so that later splits apply to both of them
+fn merge<V>(segments: &mut [Segment<V>], active: &mut Vec<usize>, i: usize)
+where
+    V: Vector2,
+{
+    let (a, b) = match (active.get(i), active.get(i + 1)) {
+        (Some(&a), Some(&b)) => (a, b),
+        _ => return,
+    };
+    let same = compare(&segments[a].left, &segments[b].left) == Ordering::Equal
+        && compare(&segments[a].right, &segments[b].right) == Ordering::Equal;
+    if same {
+        let winding = segments[b].winding;
+        segments[a].winding[0] += winding[0];
+        segments[a].winding[1] += winding[1];
+        segments[b].winding = [0, 0];
+        active.remove(i + 1);
+    }
+}
+
+/// Split two segments where they cross, or where an endpoint of one touches the other
+fn intersect<V>(
+    segments: &mut Vec<Segment<V>>,
+    events: &mut BinaryHeap<Event<V>>,
+    a: usize,
+    b: usize,
+) where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let (p, q) = (segments[a].left, segments[a].right);
+    let (r, s) = (segments[b].left, segments[b].right);
+    let size = [p, q, r, s].iter().fold(V::Scalar::ZERO, |size, v| {
+        size.maxx(v.x().abs()).maxx(v.y().abs())
+    });
+    let tolerance = size * V::Scalar::EPSILON * V::Scalar::from_usize(16);
+    // Segments that were split at rounded points are not exactly straight,
+    // so an end that is within rounding of the other segment touches it
+    let touches = |point: V, (left, right): (V, V)| {
+        orientation(left, right, point) == Ordering::Equal
+            || right.sub(left).cross(point.sub(left)).abs() <= tolerance * left.dist(right)
+    };
+    let mut touched = false;
+    for &(point, index, other) in &[
+        (r, a, (p, q)),
+        (s, a, (p, q)),
+        (p, b, (r, s)),
+        (q, b, (r, s)),
+    ] {
+        if touches(point, other) {
+            touched |= divide(segments, events, index, point);
+        }
+    }
+    let (pqr, pqs) = (orientation(p, q, r), orientation(p, q, s));
+    let (rsp, rsq) = (orientation(r, s, p), orientation(r, s, q));
+    let crosses = |x: Ordering, y: Ordering| x != Ordering::Equal && y != Ordering::Equal && x != y;
+    if touched || !(crosses(pqr, pqs) && crosses(rsp, rsq)) {
+        return;
+    }
+    let t = r.sub(p).cross(s.sub(r)) / q.sub(p).cross(s.sub(r));
+    let mut point = p.lerp(q, t);
+    // Where more than two segments cross at a point, each pair's crossing is rounded
+    // differently, so use an end of a segment that was already split there instead
+    if let Some(&end) = [p, q, r, s].iter().find(|end| end.dist(point) <= tolerance) {
+        point = end;
+    }
+    // Keep the point within both segments' bounds, so that it is exactly on
+    // horizontal and vertical segments
+    let clamp = |n: V::Scalar, [p, q, r, s]: [V::Scalar; 4]| {
+        n.maxx(p.minn(q))
+            .maxx(r.minn(s))
+            .minn(p.maxx(q))
+            .minn(r.maxx(s))
+    };
+    let point = V::new(
+        clamp(point.x(), [p.x(), q.x(), r.x(), s.x()]),
+        clamp(point.y(), [p.y(), q.y(), r.y(), s.y()]),
+    );
+    divide(segments, events, a, point);
+    divide(segments, events, b, point);
+}
+
+/// Split a segment at a point strictly between its ends, which ends it there
+/// and starts a new segment for the rest of it
+///
+/// Returns whether the segment was split.
+fn divide<V>(
+    segments: &mut Vec<Segment<V>>,
+    events: &mut BinaryHeap<Event<V>>,
+    index: usize,
+    point: V,
+) -> bool
+where
+    V: Vector2,
+{
+    let segment = segments[index];
+    if compare(&segment.left, &point) != Ordering::Less
+        || compare(&point, &segment.right) != Ordering::Less
+    {
+        return false;
+    }
+    segments[index].right = point;
+    events.push(Event {
+        point,
+        start: false,
+        segment: index,
+    });
+    add_segment(
+        segments,
+        events,
+        Segment {
+            left: point,
+            ..segment
+        },
+    );
+    true
+}
+
+/// Find the pieces that separate the inside of the result from the outside
+/// by sweeping a line across them, directed so that the inside is on their left
+///
+/// The pieces must only meet at their ends. `inside` decides whether a region
+/// with the given subject and clip winding numbers is in the result.
+fn classify<V, F>(points: &[V], pieces: &[Piece], inside: F) -> Vec<(usize, usize)>
+where
+    V: Vector2,
+    F: Fn([i32; 2]) -> bool,
+{
+    let key = |i: usize| (points[pieces[i].start], points[pieces[i].end]);
+    // Pieces end before others start, and pieces that start
+    // at the same point are added from the bottom up
+    let mut events: Vec<(usize, bool, usize)> = (0..pieces.len())
+        .flat_map(|i| vec![(pieces[i].start, true, i), (pieces[i].end, false, i)])
+        .collect();
+    events.sort_by(|a, b| {
+        a.0.cmp(&b.0).then(a.1.cmp(&b.1)).then_with(|| {
+            if below(key(a.2), key(b.2)) {
+                Ordering::Less
+            } else if below(key(b.2), key(a.2)) {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+    });
+    // The winding numbers of the region just above each piece
+    let mut above = vec![[0; 2]; pieces.len()];
+    let mut active: Vec<usize> = Vec::new();
+    let mut kept = Vec::new();
+    for (_, start, index) in events {
+        if !start {
+            if let Some(i) = active.iter().position(|&other| other == index) {
+                active.remove(i);
+            }
+            continue;
+        }
+        let i = active.partition_point(|&other| below(key(other), key(index)));
+        let under = if i > 0 { above[active[i - 1]] } else { [0; 2] };
+        let piece = pieces[index];
+        above[index] = [under[0] + piece.winding[0], under[1] + piece.winding[1]];
+        active.insert(i, index);
+        let (in_under, in_above) = (inside(under), inside(above[index]));
+        if in_under != in_above {
+            kept.push(if in_above {
+                (piece.start, piece.end)
+            } else {
+                (piece.end, piece.start)
+            });
+        }
+    }
+    kept
+}
+
+/// Link directed edges into rings, separating rings that touch at a point
+fn trace_rings<V>(points: &[V], edges: &[(usize, usize)]) -> Vec<Vec<V>>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let mut outgoing = vec![Vec::new(); points.len()];
+    for (i, &(u, _)) in edges.iter().enumerate() {
+        outgoing[u].push(i);
+    }
+    let mut used = vec![false; edges.len()];
+    let mut rings = Vec::new();
+    for start in 0..edges.len() {
+        if used[start] {
+            continue;
+        }
+        let mut ring = Vec::new();
+        let mut current = start;
+        loop {
+            used[current] = true;
+            let (u, v) = edges[current];
+            ring.push(u);
+            if v == edges[start].0 {
+                break;
+            }
+            // Take the sharpest left turn so that the ring bounds a single face
+            let back = points[u].sub(points[v]).atan();
+            let turn = |&&e: &&usize| {
+                let turn = wrap_angle(back - points[edges[e].1].sub(points[v]).atan());
+                if turn.is_zero() {
+                    V::Scalar::TAU
+                } else {
+                    turn
+                }
+            };
+            let next = outgoing[v]
+                .iter()
+                .filter(|&&e| !used[e])
+                .min_by(|a, b| turn(a).partial_cmp(&turn(b)).unwrap_or(Ordering::Equal));
+            // Every point has as many kept edges leaving it as arriving, so rings always close
+            debug_assert!(next.is_some(), "a ring of the result does not close");
+            match next {
+                Some(&next) => current = next,
+                None => break,
+            }
+        }
+        // Remove vertices that do not change the ring's direction
+        let mut i = 0;
+        while ring.len() >= 3 && i < ring.len() {
+            let n = ring.len();
+            let (prev, next) = (ring[(i + n - 1) % n], ring[(i + 1) % n]);
+            if orientation(points[prev], points[ring[i]], points[next]) == Ordering::Equal {
+                ring.remove(i);
+                i = i.saturating_sub(1);
+            } else {
+                i += 1;
+            }
+        }
+        if ring.len() >= 3 {
+            // Points are sorted, so this starts the ring from its lowest x and y
+            let first = (0..ring.len()).min_by_key(|&i| ring[i]).unwrap_or(0);
+            ring.rotate_left(first);
+            rings.push(ring.into_iter().map(|i| points[i]).collect());
+        }
+    }
+    rings
+}
+
+#[cfg(test)]
+#[test]
+fn boolean_ops() {
+    let area = |polygons: &[Vec<Vec<[f64; 2]>>]| -> f64 {
+        polygons
+            .iter()
+            .flatten()
+            .map(|ring| ring.signed_area())
+            .sum()
+    };
+    // Squares that share an edge
+    let left = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+    let right = [[1.0, 0.0], [2.0, 0.0], [2.0, 1.0], [1.0, 1.0]];
+    let union = boolean(&[left], &[right], BooleanOp::Union);
+    assert_eq!(
+        vec![vec![vec![[0.0, 0.0], [2.0, 0.0], [2.0, 1.0], [0.0, 1.0]]]],
+        union
+    );
+    assert!(boolean(&[left], &[right], BooleanOp::Intersection).is_empty());
+    assert_eq!(2.0, area(&boolean(&[left], &[right], BooleanOp::Xor)));
+    // A square with a hole, and a bar across it
+    let frame = vec![
+        vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]],
+        vec![[1.0, 1.0], [3.0, 1.0], [3.0, 3.0], [1.0, 3.0]],
+    ];
+    let bar = [[-1.0, 1.5], [5.0, 1.5], [5.0, 2.5], [-1.0, 2.5]];
+    // The bar splits the hole in two
+    let union = boolean(&frame, &[bar], BooleanOp::Union);
+    assert_eq!(1, union.len());
+    assert_eq!(3, union[0].len());
+    assert!((area(&union) - 16.0).abs() < 1e-12);
+    let cut = boolean(&frame, &[bar], BooleanOp::Difference);
+    assert_eq!(2, cut.len());
+    assert!((area(&cut) - 10.0).abs() < 1e-12);
+    for polygon in &cut {
+        assert_eq!(1, polygon.len());
+        assert!(area(&[vec![polygon[0].clone()]]) > 0.0);
+    }
+    let overlap = boolean(&frame, &[bar], BooleanOp::Intersection);
+    assert!((area(&overlap) - 2.0).abs() < 1e-12);
+    // A spike that goes out and back along the same line cancels out
+    let spiked = [
+        [0.0, 0.0],
+        [2.0, 0.0],
+        [2.0, 1.0],
+        [3.0, 1.0],
+        [2.0, 1.0],
+        [2.0, 2.0],
+        [0.0, 2.0],
+    ];
+    let union = boolean(&[spiked], &[bar], BooleanOp::Union);
+    assert_eq!(1, union.len());
+    assert!((area(&union) - 9.0).abs() < 1e-12);
+}
@@ -0,0 +1,187 @@
+//! Module for the [`Capsule`] trait
+
+use crate::{
+    circle::arc_points, Circle, FloatingScalar, FloatingVector2, Rectangle, Scalar as _, Vector2,
+};
+
+/// The scalar type of a [`Capsule`]
+pub type Scalar<T> = <<T as Capsule>::Vector as Vector2>::Scalar;
+
+/**
+Trait for manipulating capsules
+
+A capsule is the shape swept by a circle moving along a line segment.
+It is implemented for any pair of a [`Circle`] and a vector, where
+the circle is centered on the start of the segment and the vector is
+the end of the segment.
+
+```
+use vector2math::*;
+
+let capsule = f64::Caps::new([0.0, 0.0], [4.0, 0.0], 1.0);
+assert!(capsule.contains([4.5, 0.5]));
+assert!(!capsule.contains([2.0, 1.5]));
+assert_eq!(0.5, capsule.signed_distance([2.0, 1.5]));
+assert_eq!([-1.0, -1.0, 6.0, 2.0], capsule.bounding_rect::<f64::Rect>());
+```
+*/
+pub trait Capsule: Copy
+where
+    Scalar<Self>: FloatingScalar,
+{
+    /// The vector type
+    type Vector: FloatingVector2;
+    /// Create a new capsule from the start and end of its segment and a radius
+    fn new(start: Self::Vector, end: Self::Vector, radius: Scalar<Self>) -> Self;
+    /// Get the start of the capsule's segment
+    fn start(self) -> Self::Vector;
+    /// Get the end of the capsule's segment
+    fn end(self) -> Self::Vector;
+    /// Get the capsule's radius
+    fn radius(self) -> Scalar<Self>;
+    /// Transform the capsule into one with a different segment
+    fn with_segment(self, start: Self::Vector, end: Self::Vector) -> Self {
+        Self::new(start, end, self.radius())
+    }
+    /// Transform the capsule into one with a different radius
+    fn with_radius(self, radius: Scalar<Self>) -> Self {
+        Self::new(self.start(), self.end(), radius)
+    }
+    /// Get the capsule that is this one translated by some vector
+    fn translated(self, offset: Self::Vector) -> Self {
+        self.with_segment(self.start().add(offset), self.end().add(offset))
+    }
+    /// Get the length of the capsule's segment
+    fn length(self) -> Scalar<Self> {
+        self.start().dist(self.end())
+    }
+    /// Get the center of the capsule
+    fn center(self) -> Self::Vector {
+        self.start()
+            .lerp(self.end(), Scalar::<Self>::ONE / Scalar::<Self>::TWO)
+    }
+    /// Get the perimeter
+    fn perimeter(self) -> Scalar<Self> {
+        (self.length() + self.radius().abs() * Scalar::<Self>::PI) * Scalar::<Self>::TWO
+    }
+    /// Get the area
+    fn area(self) -> Scalar<Self> {
+        let radius = self.radius().abs();
+        radius * (self.length() * Scalar::<Self>::TWO + radius * Scalar::<Self>::PI)
+    }
+    /// Get the smallest axis-aligned rectangle that contains the capsule
+    fn bounding_rect<R>(self) -> R
+    where
+        R: Rectangle<Vector = Self::Vector>,
+    {
+        let radius = Self::Vector::square(self.radius().abs());
+        let min = self.start().min2(self.end()).sub(radius);
+        let max = self.start().max2(self.end()).add(radius);
+        R::new(min, max.sub(min))
+    }
+    /// Get the point on the capsule's segment closest to the given point
+    fn closest_on_segment(self, point: Self::Vector) -> Self::Vector {
+        closest_on_segment(point, self.start(), self.end())
+    }
+    /// Get the signed distance from the capsule's boundary to a point
+    ///
+    /// The distance is negative if the point is inside the capsule.
+    fn signed_distance(self, point: Self::Vector) -> Scalar<Self> {
+        point.dist(self.closest_on_segment(point)) - self.radius().abs()
+    }
+    /// Check that the capsule contains the given point. Includes the boundary.
+    fn contains(self, point: Self::Vector) -> bool {
+        self.signed_distance(point) <= Scalar::<Self>::ZERO
+    }
+    /// Check that the capsule contains all points
+    fn contains_all<I>(self, points: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        points.into_iter().all(|point| self.contains(point))
+    }
+    /// Check that the capsule contains any point
+    fn contains_any<I>(self, points: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        points.into_iter().any(|point| self.contains(point))
+    }
+    /// Get points along the capsule's boundary
+    ///
+    /// The points go around the end cap and then the start cap, in the positive
+    /// angle direction. They are spaced so that the polygon they form deviates
+    /// from the capsule by no more than `tolerance`.
+    fn outline(self, tolerance: Scalar<Self>) -> Vec<Self::Vector> {
+        let radius = self.radius().abs();
+        let (start, end) = (self.start(), self.end());
+        let direction = if start.dist(end).is_zero() {
+            Scalar::<Self>::ZERO
+        } else {
+            end.sub(start).atan()
+        };
+        let half_turn = Scalar::<Self>::PI;
+        let right_angle = half_turn / Scalar::<Self>::TWO;
+        let mut points = arc_points(end, radius, direction - right_angle, half_turn, tolerance);
+        points.extend(arc_points(
+            start,
+            radius,
+            direction + right_angle,
+            half_turn,
+            tolerance,
+        ));
+        points
+    }
+}
+
+impl<C, V> Capsule for (C, V)
+where
+    C: Circle<Vector = V>,
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    type Vector = V;
+    fn new(start: Self::Vector, end: Self::Vector, radius: Scalar<Self>) -> Self {
+        (C::new(start, radius), end)
+    }
+    fn start(self) -> Self::Vector {
+        self.0.center()
+    }
+    fn end(self) -> Self::Vector {
+        self.1
+    }
+    fn radius(self) -> Scalar<Self> {
+        self.0.radius()
+    }
+}
+
+/// Get the point on a line segment closest to the given point
+pub(crate) fn closest_on_segment<V>(point: V, start: V, end: V) -> V
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let segment = end.sub(start);
+    let squared_length = segment.squared_mag();
+    if squared_length.is_zero() {
+        return start;
+    }
+    let t = point.sub(start).dot(segment) / squared_length;
+    start.lerp(end, t.maxx(V::Scalar::ZERO).minn(V::Scalar::ONE))
+}
+
+#[cfg(test)]
+#[test]
+fn capsule() {
+    let capsule = crate::f64::Caps::new([1.0, 1.0], [1.0, 5.0], 2.0);
+    assert_eq!(-2.0, capsule.signed_distance([1.0, 3.0]));
+    assert_eq!(1.0, capsule.signed_distance([1.0, 8.0]));
+    assert_eq!(1.0, capsule.signed_distance([-2.0, 2.0]));
+    assert!((capsule.area() - (16.0 + 4.0 * f64::PI)).abs() < 1e-12);
+    assert!((capsule.perimeter() - (8.0 + 4.0 * f64::PI)).abs() < 1e-12);
+    let outline = capsule.outline(0.01);
+    assert!(outline[0].dist([3.0, 5.0]) < 1e-12);
+    for point in outline {
+        assert!(capsule.signed_distance(point).abs() < 1e-12);
+    }
+}
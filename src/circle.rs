@@ -1,6 +1,6 @@
 //! Module for the [`Circle`] trait
 
-use crate::{FloatingScalar, FloatingVector2, Scalar as _, Vector2};
+use crate::{Ellipse, FloatingScalar, FloatingVector2, Scalar as _, Triangle, Vector2};
 
 /// The scalar type of a [`Circle`]
 pub type Scalar<T> = <<T as Circle>::Vector as Vector2>::Scalar;
@@ -82,6 +82,31 @@ where
             radius * Scalar::<Self>::TWO,
         ]
     }
+    /// Get this circle as an ellipse
+    ///
+    /// This is useful for applying non-uniform [`Transform`](crate::Transform)s,
+    /// which do not map circles to circles.
+    fn to_ellipse<E>(self) -> E
+    where
+        E: Ellipse<Vector = Self::Vector>,
+    {
+        E::from_circle(self)
+    }
+    /// Get points along the circle's boundary
+    ///
+    /// The points are spaced so that the polygon they form deviates
+    /// from the circle by no more than `tolerance`.
+    fn outline(self, tolerance: Scalar<Self>) -> Vec<Self::Vector> {
+        let mut points = arc_points(
+            self.center(),
+            self.radius().abs(),
+            Scalar::<Self>::ZERO,
+            Scalar::<Self>::TAU,
+            tolerance,
+        );
+        points.pop();
+        points
+    }
     /// Check that the circle contains the given point
     fn contains(self, point: Self::Vector) -> bool {
         self.center().dist(point) <= self.radius().abs()
@@ -106,6 +131,42 @@ where
     {
         points.into_iter().any(|point| self.contains(point))
     }
+    /// Get the smallest circle that contains all the points
+    ///
+    /// This uses Welzl's algorithm, which takes expected linear time.
+    ///
+    /// Returns `None` if the iterator is empty
+    fn bounding<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        let mut points: Vec<Self::Vector> = points.into_iter().collect();
+        shuffle(&mut points);
+        let (&first, _) = points.split_first()?;
+        let mut circle = (first, Scalar::<Self>::ZERO);
+        let half = Scalar::<Self>::ONE / Scalar::<Self>::TWO;
+        for i in 1..points.len() {
+            if encloses(circle, points[i]) {
+                continue;
+            }
+            circle = (points[i], Scalar::<Self>::ZERO);
+            for j in 0..i {
+                if encloses(circle, points[j]) {
+                    continue;
+                }
+                circle = (
+                    points[i].lerp(points[j], half),
+                    points[i].dist(points[j]) * half,
+                );
+                for k in 0..j {
+                    if !encloses(circle, points[k]) {
+                        circle = circumscribe(points[i], points[j], points[k]);
+                    }
+                }
+            }
+        }
+        Some(Self::new(circle.0, circle.1))
+    }
 }
 
 impl<S, V> Circle for (V, S)
@@ -124,3 +185,105 @@ where
         self.1
     }
 }
+
+/// Check if a circle contains a point, allowing for rounding error
+fn encloses<V>((center, radius): (V, V::Scalar), point: V) -> bool
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let tolerance = V::Scalar::ONE + V::Scalar::EPSILON * V::Scalar::from_usize(16);
+    center.dist(point) <= radius * tolerance
+}
+
+/// Get the smallest circle that passes through three points, or
+/// through the two furthest ones if they are collinear
+fn circumscribe<V>(a: V, b: V, c: V) -> (V, V::Scalar)
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    if b.sub(a).cross(c.sub(a)).is_zero() {
+        let half = V::Scalar::ONE / V::Scalar::TWO;
+        let (p, q) = [(a, b), (b, c), (c, a)]
+            .iter()
+            .copied()
+            .fold((a, a), |(p, q), (r, s)| {
+                if r.squared_dist(s) > p.squared_dist(q) {
+                    (r, s)
+                } else {
+                    (p, q)
+                }
+            });
+        (p.lerp(q, half), p.dist(q) * half)
+    } else {
+        [a, b, c].circumcircle()
+    }
+}
+
+/// Shuffle items in a fixed pseudorandom order
+fn shuffle<T>(items: &mut [T]) {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for i in (1..items.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        items.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+}
+
+/// Get points along a circular arc, including both endpoints
+///
+/// The points are spaced so that the polyline they form deviates
+/// from the arc by no more than `tolerance`.
+pub(crate) fn arc_points<V>(
+    center: V,
+    radius: V::Scalar,
+    start: V::Scalar,
+    sweep: V::Scalar,
+    tolerance: V::Scalar,
+) -> Vec<V>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let one = V::Scalar::ONE;
+    let max_step = if tolerance >= radius {
+        V::Scalar::PI / V::Scalar::TWO
+    } else {
+        let tolerance = tolerance.maxx(radius * V::Scalar::EPSILON.sqrt());
+        (one - tolerance / radius).acos() * V::Scalar::TWO
+    };
+    let mut steps = one;
+    while sweep.abs() / steps > max_step {
+        steps = steps + one;
+    }
+    let mut points = Vec::new();
+    let mut i = V::Scalar::ZERO;
+    while i <= steps {
+        points.push(center.add(V::from_angle(start + sweep * i / steps).mul(radius)));
+        i = i + one;
+    }
+    points
+}
+
+#[cfg(test)]
+#[test]
+fn circle() {
+    let points = [
+        [0.0, 0.0],
+        [4.0, 0.0],
+        [2.0, 1.0],
+        [1.0, -1.0],
+        [3.0, 0.5],
+        [2.0, -2.0],
+    ];
+    let circle = crate::f64::Circ::bounding(points.iter().copied()).unwrap();
+    assert!(circle.center().dist([2.0, 0.0]) < 1e-12);
+    assert!((circle.radius() - 2.0).abs() < 1e-12);
+    let triangle = [[0.0, 0.0], [4.0, 0.0], [2.0, 3.0]];
+    let circle = crate::f64::Circ::bounding(triangle.iter().copied()).unwrap();
+    let circumcircle: crate::f64::Circ = triangle.circumcircle();
+    assert!(circle.center().dist(circumcircle.center()) < 1e-12);
+    assert!(crate::f64::Circ::bounding(Vec::new()).is_none());
+}
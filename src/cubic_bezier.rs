@@ -0,0 +1,299 @@
+//! Module for the [`CubicBezier`] trait
+
+use crate::{
+    capsule::closest_on_segment, FloatingScalar, FloatingVector2, Rectangle, Scalar as _, Vector2,
+};
+
+/// The scalar type of a [`CubicBezier`]
+pub type Scalar<T> = <<T as CubicBezier>::Vector as Vector2>::Scalar;
+
+/// The maximum number of times a curve is subdivided when flattening or measuring it
+const MAX_DEPTH: usize = 16;
+
+/**
+Trait for manipulating cubic Bézier curves
+
+It is implemented for `[V; 4]` and `(V, V, V, V)` where `V` implements [`FloatingVector2`].
+The first and last control points are the curve's endpoints.
+```
+use vector2math::*;
+
+let curve = [[0.0, 0.0], [0.0, 2.0], [2.0, 2.0], [2.0, 0.0]];
+assert_eq!([1.0, 1.5], curve.eval(0.5));
+assert_eq!([3.0, 0.0], curve.derivative(0.5));
+let bounds: f64::Rect = curve.bounding_rect();
+assert_eq!([0.0, 0.0, 2.0, 1.5], bounds);
+let (left, right) = curve.split(0.5);
+assert_eq!(curve.eval(0.25), left.eval(0.5));
+assert_eq!(curve.eval(0.75), right.eval(0.5));
+```
+*/
+pub trait CubicBezier: Copy
+where
+    Scalar<Self>: FloatingScalar,
+{
+    /// The vector type
+    type Vector: FloatingVector2;
+    /// Create a new curve from its control points
+    fn from_control_points(points: [Self::Vector; 4]) -> Self;
+    /// Get the curve's control points
+    fn control_points(self) -> [Self::Vector; 4];
+    /// Get the start point
+    fn start(self) -> Self::Vector {
+        self.control_points()[0]
+    }
+    /// Get the end point
+    fn end(self) -> Self::Vector {
+        self.control_points()[3]
+    }
+    /// Get the curve that goes the opposite direction
+    fn reversed(self) -> Self {
+        let [a, b, c, d] = self.control_points();
+        Self::from_control_points([d, c, b, a])
+    }
+    /// Get the point on the curve at the parameter `t`, which goes from 0 to 1
+    fn eval(self, t: Scalar<Self>) -> Self::Vector {
+        let [a, b, c, d] = self.control_points();
+        let ab = a.lerp(b, t);
+        let bc = b.lerp(c, t);
+        let cd = c.lerp(d, t);
+        ab.lerp(bc, t).lerp(bc.lerp(cd, t), t)
+    }
+    /// Get the derivative of the curve with respect to its parameter at `t`
+    fn derivative(self, t: Scalar<Self>) -> Self::Vector {
+        let [a, b, c, d] = self.control_points();
+        let three = Scalar::<Self>::TWO + Scalar::<Self>::ONE;
+        let ab = b.sub(a);
+        let bc = c.sub(b);
+        let cd = d.sub(c);
+        ab.lerp(bc, t).lerp(bc.lerp(cd, t), t).mul(three)
+    }
+    /// Get the second derivative of the curve with respect to its parameter at `t`
+    fn second_derivative(self, t: Scalar<Self>) -> Self::Vector {
+        let [a, b, c, d] = self.control_points();
+        let six = (Scalar::<Self>::TWO + Scalar::<Self>::ONE) * Scalar::<Self>::TWO;
+        let abc = c.sub(b.mul(Scalar::<Self>::TWO)).add(a);
+        let bcd = d.sub(c.mul(Scalar::<Self>::TWO)).add(b);
+        abc.lerp(bcd, t).mul(six)
+    }
+    /// Split the curve into two at the parameter `t`
+    fn split(self, t: Scalar<Self>) -> (Self, Self) {
+        let [a, b, c, d] = self.control_points();
+        let ab = a.lerp(b, t);
+        let bc = b.lerp(c, t);
+        let cd = c.lerp(d, t);
+        let abc = ab.lerp(bc, t);
+        let bcd = bc.lerp(cd, t);
+        let mid = abc.lerp(bcd, t);
+        (
+            Self::from_control_points([a, ab, abc, mid]),
+            Self::from_control_points([mid, bcd, cd, d]),
+        )
+    }
+    /// Get the parameters in `(0, 1)` where the curve's tangent is
+    /// horizontal or vertical
+    fn extrema(self) -> Vec<Scalar<Self>> {
+        let [a, b, c, d] = self.control_points();
+        let three = Scalar::<Self>::TWO + Scalar::<Self>::ONE;
+        // The derivative is the quadratic `qa * t² + qb * t + qc`, divided by 3
+        let qa = d.sub(a).add(b.sub(c).mul(three));
+        let qb = a
+            .sub(b.mul(Scalar::<Self>::TWO))
+            .add(c)
+            .mul(Scalar::<Self>::TWO);
+        let qc = b.sub(a);
+        let mut roots = quadratic_roots(qa.x(), qb.x(), qc.x());
+        roots.extend(quadratic_roots(qa.y(), qb.y(), qc.y()));
+        roots.retain(|&t| Scalar::<Self>::ZERO < t && t < Scalar::<Self>::ONE);
+        roots
+    }
+    /// Get the smallest axis-aligned rectangle that contains the curve
+    fn bounding_rect<R>(self) -> R
+    where
+        R: Rectangle<Vector = Self::Vector>,
+    {
+        let mut min = self.start().min2(self.end());
+        let mut max = self.start().max2(self.end());
+        for t in self.extrema() {
+            let point = self.eval(t);
+            min = min.min2(point);
+            max = max.max2(point);
+        }
+        R::new(min, max.sub(min))
+    }
+    /// Check if the curve is within `tolerance` of the line segment between its endpoints
+    fn is_flat(self, tolerance: Scalar<Self>) -> bool {
+        // The curve is inside the hull of its control points, so
+        // it is no further from the segment than they are
+        let [a, b, c, d] = self.control_points();
+        let dist = |p: Self::Vector| p.dist(closest_on_segment(p, a, d));
+        dist(b).maxx(dist(c)) <= tolerance
+    }
+    /// Get an estimate of the curve's length
+    ///
+    /// The curve is subdivided until the estimate of each piece is within `tolerance`.
+    fn arc_length(self, tolerance: Scalar<Self>) -> Scalar<Self> {
+        arc_length(self, tolerance, MAX_DEPTH)
+    }
+    /// Get the parameter of the point on the curve closest to the given point
+    fn nearest_t(self, point: Self::Vector) -> Scalar<Self> {
+        const SAMPLES: usize = 16;
+        let zero = Scalar::<Self>::ZERO;
+        let one = Scalar::<Self>::ONE;
+        let samples = Scalar::<Self>::from_usize(SAMPLES);
+        let mut best_t = zero;
+        let mut best_dist = point.squared_dist(self.start());
+        for i in 1..=SAMPLES {
+            let t = Scalar::<Self>::from_usize(i) / samples;
+            let dist = point.squared_dist(self.eval(t));
+            if dist < best_dist {
+                best_t = t;
+                best_dist = dist;
+            }
+        }
+        // Refine with Newton's method on the derivative of the squared distance
+        let mut t = best_t;
+        for _ in 0..8 {
+            let offset = self.eval(t).sub(point);
+            let d1 = self.derivative(t);
+            let numerator = offset.dot(d1);
+            let denominator = d1.squared_mag() + offset.dot(self.second_derivative(t));
+            if denominator.is_zero() {
+                break;
+            }
+            t = (t - numerator / denominator).maxx(zero).minn(one);
+        }
+        if point.squared_dist(self.eval(t)) <= best_dist {
+            t
+        } else {
+            best_t
+        }
+    }
+    /// Get the point on the curve closest to the given point
+    fn nearest_point(self, point: Self::Vector) -> Self::Vector {
+        self.eval(self.nearest_t(point))
+    }
+    /// Get points along the curve, including both endpoints
+    ///
+    /// The curve is adaptively subdivided so that the polyline formed by
+    /// the points deviates from the curve by no more than `tolerance`.
+    fn flatten(self, tolerance: Scalar<Self>) -> Vec<Self::Vector> {
+        let mut points = vec![self.start()];
+        flatten_into(self, tolerance, MAX_DEPTH, &mut points);
+        points
+    }
+}
+
+impl<V> CubicBezier for [V; 4]
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    type Vector = V;
+    fn from_control_points(points: [Self::Vector; 4]) -> Self {
+        points
+    }
+    fn control_points(self) -> [Self::Vector; 4] {
+        self
+    }
+}
+
+impl<V> CubicBezier for (V, V, V, V)
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    type Vector = V;
+    fn from_control_points([a, b, c, d]: [Self::Vector; 4]) -> Self {
+        (a, b, c, d)
+    }
+    fn control_points(self) -> [Self::Vector; 4] {
+        [self.0, self.1, self.2, self.3]
+    }
+}
+
+/// Get the real roots of `a * t² + b * t + c`
+pub(crate) fn quadratic_roots<S>(a: S, b: S, c: S) -> Vec<S>
+where
+    S: FloatingScalar,
+{
+    if a.abs() <= S::EPSILON * (b.abs() + c.abs()) || a.is_zero() {
+        return if b.is_zero() {
+            Vec::new()
+        } else {
+            vec![-c / b]
+        };
+    }
+    let discriminant = b.square() - (S::TWO + S::TWO) * a * c;
+    if discriminant < S::ZERO {
+        Vec::new()
+    } else {
+        let sqrt = discriminant.sqrt();
+        let two_a = S::TWO * a;
+        vec![(-b - sqrt) / two_a, (-b + sqrt) / two_a]
+    }
+}
+
+fn arc_length<C>(curve: C, tolerance: Scalar<C>, depth: usize) -> Scalar<C>
+where
+    C: CubicBezier,
+    Scalar<C>: FloatingScalar,
+{
+    let [a, b, c, d] = curve.control_points();
+    let chord = a.dist(d);
+    let net = a.dist(b) + b.dist(c) + c.dist(d);
+    if depth == 0 || net - chord <= tolerance {
+        // This weighting of the chord and control net lengths is exact for
+        // straight lines and converges quickly for curves
+        let three = Scalar::<C>::TWO + Scalar::<C>::ONE;
+        (chord * Scalar::<C>::TWO + net) / three
+    } else {
+        let half = Scalar::<C>::ONE / Scalar::<C>::TWO;
+        let (left, right) = curve.split(half);
+        arc_length(left, tolerance * half, depth - 1)
+            + arc_length(right, tolerance * half, depth - 1)
+    }
+}
+
+fn flatten_into<C>(curve: C, tolerance: Scalar<C>, depth: usize, points: &mut Vec<C::Vector>)
+where
+    C: CubicBezier,
+    Scalar<C>: FloatingScalar,
+{
+    if depth == 0 || curve.is_flat(tolerance) {
+        points.push(curve.end());
+    } else {
+        let (left, right) = curve.split(Scalar::<C>::ONE / Scalar::<C>::TWO);
+        flatten_into(left, tolerance, depth - 1, points);
+        flatten_into(right, tolerance, depth - 1, points);
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn cubic_bezier() {
+    let line = [[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [3.0, 3.0]];
+    assert!((line.arc_length(1e-9) - 18f64.sqrt()).abs() < 1e-9);
+    assert_eq!(2, line.flatten(0.1).len());
+    let curve = ([0.0, 0.0], [1.0, 3.0], [3.0, -2.0], [4.0, 1.0]);
+    let flat = curve.flatten(0.001);
+    let polyline_length: f64 = flat.windows(2).map(|w| w[0].dist(w[1])).sum();
+    assert!((curve.arc_length(1e-6) - polyline_length).abs() < 1e-2);
+    let bounds: [f64; 4] = curve.bounding_rect();
+    for i in 0..=100 {
+        let point = curve.eval(i as f64 / 100.0);
+        assert!(bounds.outer_margin(1e-9).contains(point));
+    }
+    let target = curve
+        .eval(0.3)
+        .add(curve.derivative(0.3).perp().unit().mul(0.1));
+    assert!((curve.nearest_t(target) - 0.3).abs() < 1e-9);
+    // A curve that doubles back past the end of its chord
+    let overshoot = [[0.0, 0.0], [5.0, 0.0], [5.0, 0.0], [1.0, 0.0]];
+    assert!(!overshoot.is_flat(0.01));
+    let reach = overshoot.eval(overshoot.extrema()[0]).x();
+    assert!((reach - 3.885).abs() < 1e-3);
+    let flat = overshoot.flatten(0.01);
+    let furthest = flat.iter().fold(0.0, |max: f64, p| max.max(p.x()));
+    assert!((furthest - reach).abs() <= 0.01);
+}
@@ -0,0 +1,764 @@
+//! Module for the [`Delaunay`] type
+
+use std::{
+    cmp::Ordering,
+    collections::{HashSet, VecDeque},
+};
+
+use crate::{
+    hull::{robust_in_circle, robust_orientation},
+    polygon::clip_half_plane,
+    FloatingScalar, FloatingVector2, Rectangle, Scalar as _,
+};
+
+/// The twin of a half-edge on the convex hull, which has no triangle on its other side
+const NONE: usize = usize::MAX;
+
+/**
+A Delaunay triangulation of a set of points
+
+No point is inside the circumcircle of any triangle, so the triangles are as
+close to equilateral as possible. Edges between points can be forced into the
+triangulation with [`Delaunay::with_constraints`].
+
+The dual of the triangulation is the Voronoi diagram, which divides the plane
+into cells of the points closest to each site.
+```
+use vector2math::*;
+
+let sites = [[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0], [1.0, 2.0]];
+let delaunay = Delaunay::new(&sites);
+assert_eq!(4, delaunay.triangles().len());
+assert_eq!(Some(4), delaunay.nearest([1.5, 2.5]));
+
+let cells = delaunay.voronoi([0.0, 0.0, 4.0, 4.0]);
+let area: f64 = cells.iter().map(|cell| cell.signed_area().abs()).sum();
+assert!((area - 16.0).abs() < 1e-9);
+```
+*/
+#[derive(Debug, Clone)]
+pub struct Delaunay<V> {
+    points: Vec<V>,
+    triangles: Vec<[usize; 3]>,
+    /// The twin of each half-edge, or [`NONE`] on the hull
+    ///
+    /// Half-edge `3 * t + i` goes from vertex `i` of triangle `t` to the next one.
+    halfedges: Vec<usize>,
+    /// A half-edge leaving each point, or [`NONE`] if it is in no triangle
+    incident: Vec<usize>,
+    /// The Delaunay neighbors of each point, which are unaffected by constraints
+    neighbors: Vec<Vec<usize>>,
+    /// The index of the first point equal to each point
+    canonical: Vec<usize>,
+    constraints: Vec<[usize; 2]>,
+    /// The constrained edges with the smaller index first
+    constrained: HashSet<[usize; 2]>,
+}
+
+impl<V> Delaunay<V>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    /// Triangulate a set of points
+    ///
+    /// Duplicate points are only triangulated once.
+    /// If all of the points are collinear, there are no triangles.
+    pub fn new(points: &[V]) -> Self {
+        let n = points.len();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| {
+            let (a, b) = (points[a], points[b]);
+            a.x()
+                .partial_cmp(&b.x())
+                .unwrap_or(Ordering::Equal)
+                .then(a.y().partial_cmp(&b.y()).unwrap_or(Ordering::Equal))
+        });
+        let mut canonical: Vec<usize> = (0..n).collect();
+        for w in order.windows(2) {
+            if points[w[0]].squared_dist(points[w[1]]).is_zero() {
+                canonical[w[1]] = canonical[w[0]];
+            }
+        }
+        order.retain(|&i| canonical[i] == i);
+        let (triangles, halfedges) = bowyer_watson(points, &order);
+        let mut incident = vec![NONE; n];
+        for (e, &twin) in halfedges.iter().enumerate() {
+            let origin = triangles[e / 3][e % 3];
+            if incident[origin] == NONE || twin == NONE {
+                incident[origin] = e;
+            }
+        }
+        let mut neighbors = vec![Vec::new(); n];
+        let mut connect = |u: usize, v: usize| {
+            if !neighbors[u].contains(&v) {
+                neighbors[u].push(v);
+                neighbors[v].push(u);
+            }
+        };
+        if triangles.is_empty() {
+            // The points are collinear, so each is only adjacent to the ones beside it
+            for w in order.windows(2) {
+                connect(w[0], w[1]);
+            }
+        } else {
+            for &[a, b, c] in &triangles {
+                connect(a, b);
+                connect(b, c);
+                connect(c, a);
+            }
+        }
+        for i in 0..n {
+            if canonical[i] != i {
+                neighbors[i] = neighbors[canonical[i]].clone();
+            }
+        }
+        Delaunay {
+            points: points.to_vec(),
+            triangles,
+            halfedges,
+            incident,
+            neighbors,
+            canonical,
+            constraints: Vec::new(),
+            constrained: HashSet::new(),
+        }
+    }
+    /// Force edges between pairs of points into the triangulation
+    ///
+    /// Each edge is given as a pair of point indices. Edges that pass through
+    /// other points are split at those points. Triangles away from the
+    /// constrained edges remain Delaunay.
+    ///
+    /// Constrained edges cannot cross each other, so an edge that crosses one
+    /// given before it is skipped and left out of [`Delaunay::constraints`].
+    pub fn with_constraints(mut self, edges: &[[usize; 2]]) -> Self {
+        for &[a, b] in edges {
+            if a < self.points.len() && b < self.points.len() {
+                self.constrain(self.canonical[a], self.canonical[b]);
+            }
+        }
+        self
+    }
+    /// Get the triangulated points
+    pub fn points(&self) -> &[V] {
+        &self.points
+    }
+    /// Get the triangles as indices into the points
+    ///
+    /// Triangles are counter-clockwise in a Y-up coordinate system.
+    pub fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+    /// Get the constrained edges, including the pieces of any that were split
+    pub fn constraints(&self) -> &[[usize; 2]] {
+        &self.constraints
+    }
+    /// Get the indices of the points whose Voronoi cells border the given point's cell
+    ///
+    /// These are the point's neighbors in the unconstrained triangulation.
+    pub fn neighbors(&self, index: usize) -> &[usize] {
+        &self.neighbors[index]
+    }
+    /// Get the index of the point closest to the given point
+    ///
+    /// This is the site of the Voronoi cell that contains the point.
+    /// Returns `None` if there are no points.
+    pub fn nearest(&self, point: V) -> Option<usize> {
+        let mut current = *self.canonical.first()?;
+        let mut dist = point.squared_dist(self.points[current]);
+        // Greedily walking toward the point along Delaunay edges always reaches the nearest site
+        while let Some((next, next_dist)) = self.neighbors[current]
+            .iter()
+            .map(|&i| (i, point.squared_dist(self.points[i])))
+            .filter(|&(_, d)| d < dist)
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+        {
+            current = next;
+            dist = next_dist;
+        }
+        Some(current)
+    }
+    /// Get the Voronoi cell of each point, clipped to a rectangle
+    ///
+    /// Each cell is a convex polygon that winds the same way as the rectangle's
+    /// corners. Cells of points outside the rectangle may be empty.
+    pub fn voronoi<R>(&self, bounds: R) -> Vec<Vec<V>>
+    where
+        R: Rectangle<Vector = V>,
+    {
+        let half = V::Scalar::ONE / V::Scalar::TWO;
+        self.points
+            .iter()
+            .zip(&self.neighbors)
+            .map(|(&site, neighbors)| {
+                let mut cell = bounds.corners().to_vec();
+                for &j in neighbors {
+                    let other = self.points[j];
+                    if other.squared_dist(site).is_zero() {
+                        continue;
+                    }
+                    // Keep the part of the cell closer to the site than to its neighbor
+                    let mid = site.lerp(other, half);
+                    let normal = other.sub(site);
+                    cell = clip_half_plane(&cell, |p| p.sub(mid).dot(normal));
+                }
+                cell
+            })
+            .collect()
+    }
+    /// Insert a constrained edge between two distinct points
+    fn constrain(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        let (pa, pb) = (self.points[a], self.points[b]);
+        // Split the edge at the nearest point that lies on it
+        let on_edge = (0..self.points.len())
+            .filter(|&c| self.canonical[c] == c && c != a && c != b)
+            .filter(|&c| {
+                let pc = self.points[c];
+                robust_orientation(pa, pb, pc) == Ordering::Equal
+                    && pc.sub(pa).dot(pb.sub(pa)) > V::Scalar::ZERO
+                    && pc.sub(pb).dot(pa.sub(pb)) > V::Scalar::ZERO
+            })
+            .min_by(|&c, &d| {
+                pa.squared_dist(self.points[c])
+                    .partial_cmp(&pa.squared_dist(self.points[d]))
+                    .unwrap_or(Ordering::Equal)
+            });
+        if let Some(c) = on_edge {
+            self.constrain(a, c);
+            self.constrain(c, b);
+            return;
+        }
+        let key = [a.min(b), a.max(b)];
+        if self.constrained.contains(&key) {
+            return;
+        }
+        let crossing = if self.triangles.is_empty() {
+            Vec::new()
+        } else {
+            self.crossing_edges(a, b)
+        };
+        if crossing
+            .iter()
+            .any(|&[u, v]| self.constrained.contains(&[u.min(v), u.max(v)]))
+        {
+            return;
+        }
+        self.constraints.push([a, b]);
+        self.constrained.insert(key);
+        // Flip edges that cross the constrained edge until none do
+        let mut crossing: VecDeque<[usize; 2]> = crossing.into_iter().collect();
+        let mut new_edges = Vec::new();
+        while let Some([u, v]) = crossing.pop_front() {
+            let e = match self.find_edge(u, v) {
+                Some(e) => e,
+                None => continue,
+            };
+            let f = self.halfedges[e];
+            let (p, q) = (self.apex(e), self.apex(f));
+            let [pu, pv, pp, pq] = [u, v, p, q].map(|i| self.points[i]);
+            if segments_cross(pp, pq, pu, pv) {
+                self.flip(e);
+                if segments_cross(pa, pb, pp, pq) {
+                    crossing.push_back([p, q]);
+                } else {
+                    new_edges.push([p, q]);
+                }
+            } else {
+                // The quadrilateral is not convex yet, so come back once its neighbors have flipped
+                crossing.push_back([u, v]);
+            }
+        }
+        self.legalize(new_edges);
+    }
+    /// Get the edges that cross the segment between two points, in order from `a` to `b`
+    ///
+    /// No point may lie on the segment. The result is empty if the points share an edge.
+    fn crossing_edges(&self, a: usize, b: usize) -> Vec<[usize; 2]> {
+        let (pa, pb) = (self.points[a], self.points[b]);
+        let side = |i: usize| robust_orientation(pa, pb, self.points[i]);
+        let mut crossing = Vec::new();
+        // Find the triangle around `a` that the segment leaves through
+        let mut edge = None;
+        for e in self.outgoing(a) {
+            let (x, y) = (self.points_of(e)[1], self.apex(e));
+            if x == b || y == b {
+                return crossing;
+            }
+            if side(x) == Ordering::Less && side(y) == Ordering::Greater {
+                edge = Some(next(e));
+                break;
+            }
+        }
+        // Walk across triangles until reaching `b`
+        while let Some(e) = edge {
+            let [x, y] = self.points_of(e);
+            crossing.push([x, y]);
+            let twin = self.halfedges[e];
+            if twin == NONE {
+                break;
+            }
+            let z = self.apex(twin);
+            edge = if z == b {
+                None
+            } else if side(z) == Ordering::Greater {
+                Some(next(twin))
+            } else {
+                Some(prev(twin))
+            };
+        }
+        crossing
+    }
+    /// Flip edges until none of the given ones or their surroundings violate the Delaunay condition
+    fn legalize(&mut self, mut edges: Vec<[usize; 2]>) {
+        while let Some([u, v]) = edges.pop() {
+            if self.constrained.contains(&[u.min(v), u.max(v)]) {
+                continue;
+            }
+            let e = match self.find_edge(u, v) {
+                Some(e) if self.halfedges[e] != NONE => e,
+                _ => continue,
+            };
+            let (p, q) = (self.apex(e), self.apex(self.halfedges[e]));
+            let [pu, pv, pp, pq] = [u, v, p, q].map(|i| self.points[i]);
+            if robust_in_circle(pu, pv, pp, pq) == Ordering::Greater
+                && segments_cross(pp, pq, pu, pv)
+            {
+                self.flip(e);
+                edges.extend_from_slice(&[[u, q], [q, v], [v, p], [p, u]]);
+            }
+        }
+    }
+    /// Get the start and end points of a half-edge
+    fn points_of(&self, e: usize) -> [usize; 2] {
+        [
+            self.triangles[e / 3][e % 3],
+            self.triangles[e / 3][(e + 1) % 3],
+        ]
+    }
+    /// Get the point opposite a half-edge in its triangle
+    fn apex(&self, e: usize) -> usize {
+        self.triangles[e / 3][(e + 2) % 3]
+    }
+    /// Get the half-edges leaving a point
+    fn outgoing(&self, u: usize) -> Vec<usize> {
+        let start = self.incident[u];
+        let mut edges = Vec::new();
+        if start == NONE {
+            return edges;
+        }
+        // Rotate clockwise until coming back around or reaching the hull
+        let mut e = start;
+        loop {
+            edges.push(e);
+            e = self.halfedges[prev(e)];
+            if e == start {
+                return edges;
+            }
+            if e == NONE {
+                break;
+            }
+        }
+        // Rotate counter-clockwise from the start to cover the rest
+        let mut e = start;
+        while self.halfedges[e] != NONE {
+            e = next(self.halfedges[e]);
+            edges.push(e);
+        }
+        edges
+    }
+    /// Find the half-edge from `u` to `v`
+    fn find_edge(&self, u: usize, v: usize) -> Option<usize> {
+        self.outgoing(u)
+            .into_iter()
+            .find(|&e| self.points_of(e)[1] == v)
+    }
+    /// Replace an interior edge with the one between the points opposite it
+    fn flip(&mut self, e: usize) {
+        flip(&mut self.triangles, &mut self.halfedges, e);
+        let f = self.halfedges[prev(e)];
+        for &h in &[e, next(e), prev(e), f, next(f), prev(f)] {
+            let origin = self.triangles[h / 3][h % 3];
+            self.incident[origin] = h;
+        }
+    }
+}
+
+/// Get the next half-edge around a triangle
+fn next(e: usize) -> usize {
+    e - e % 3 + (e + 1) % 3
+}
+
+/// Get the previous half-edge around a triangle
+fn prev(e: usize) -> usize {
+    e - e % 3 + (e + 2) % 3
+}
+
+/// Make two half-edges each other's twin
+fn link(halfedges: &mut [usize], a: usize, b: usize) {
+    halfedges[a] = b;
+    if b != NONE {
+        halfedges[b] = a;
+    }
+}
+
+/// Replace the edge of half-edge `e` with the one between the points opposite it
+///
+/// Afterwards, the new edge is `prev(e)` and its twin.
+fn flip(triangles: &mut [[usize; 3]], halfedges: &mut [usize], e: usize) {
+    let f = halfedges[e];
+    let (e1, e2, f1, f2) = (next(e), prev(e), next(f), prev(f));
+    let [u, v] = [triangles[e / 3][e % 3], triangles[e / 3][(e + 1) % 3]];
+    let (p, q) = (triangles[e / 3][(e + 2) % 3], triangles[f / 3][(f + 2) % 3]);
+    // The triangles `u, v, p` and `v, u, q` become `q, v, p` and `p, u, q`
+    let set = |triangles: &mut [[usize; 3]], h: usize, point: usize| {
+        triangles[h / 3][h % 3] = point;
+    };
+    set(triangles, e, q);
+    set(triangles, e1, v);
+    set(triangles, e2, p);
+    set(triangles, f, p);
+    set(triangles, f1, u);
+    set(triangles, f2, q);
+    let (outer_e2, outer_f2) = (halfedges[e2], halfedges[f2]);
+    link(halfedges, e, outer_f2);
+    link(halfedges, f, outer_e2);
+    link(halfedges, e2, f2);
+}
+
+/// Triangulate points by inserting them one at a time, returning the triangles and their half-edge twins
+///
+/// A ghost point at infinity forms a triangle with each hull edge, so points
+/// outside the hull are inserted the same way as points inside it.
+fn bowyer_watson<V>(points: &[V], order: &[usize]) -> (Vec<[usize; 3]>, Vec<usize>)
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let ghost = points.len();
+    let mut order = hilbert_order(points, order);
+    // Start with the first triangle that is not flat
+    let third = (2..order.len()).find(|&k| {
+        robust_orientation(points[order[0]], points[order[1]], points[order[k]]) != Ordering::Equal
+    });
+    let third = match third {
+        Some(third) => order.remove(third),
+        None => return (Vec::new(), Vec::new()),
+    };
+    let (a, b) = (order[0], order[1]);
+    let (b, c) = match robust_orientation(points[a], points[b], points[third]) {
+        Ordering::Greater => (b, third),
+        _ => (third, b),
+    };
+    let mut mesh = Mesh {
+        points,
+        ghost,
+        triangles: vec![[a, b, c], [b, a, ghost], [c, b, ghost], [a, c, ghost]],
+        halfedges: vec![3, 6, 9, 0, 11, 7, 1, 5, 10, 2, 8, 4],
+        marks: vec![0; 4],
+        seed: 0,
+    };
+    let mut start = 0;
+    for (stamp, &i) in order.iter().enumerate().skip(2) {
+        start = mesh.insert(i, start, stamp + 1);
+    }
+    // Drop the ghost triangles and cut the hull edges' links to them
+    let Mesh {
+        triangles,
+        halfedges,
+        ..
+    } = mesh;
+    let mut index = vec![NONE; triangles.len()];
+    let mut finite = Vec::new();
+    for (t, triangle) in triangles.iter().enumerate() {
+        if !triangle.contains(&ghost) {
+            index[t] = finite.len();
+            finite.push(*triangle);
+        }
+    }
+    let mut twins = vec![NONE; finite.len() * 3];
+    for (e, &twin) in halfedges.iter().enumerate() {
+        if index[e / 3] != NONE && index[twin / 3] != NONE {
+            twins[index[e / 3] * 3 + e % 3] = index[twin / 3] * 3 + twin % 3;
+        }
+    }
+    (finite, twins)
+}
+
+/// A triangulation with ghost triangles, used while inserting points
+struct Mesh<'a, V> {
+    points: &'a [V],
+    ghost: usize,
+    triangles: Vec<[usize; 3]>,
+    halfedges: Vec<usize>,
+    /// The last insertion each triangle was found to conflict with
+    marks: Vec<usize>,
+    /// The state of the random number generator used to walk across the mesh
+    seed: usize,
+}
+
+impl<'a, V> Mesh<'a, V>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    /// Insert a point, starting the search at a finite triangle and returning a new finite triangle
+    fn insert(&mut self, i: usize, start: usize, stamp: usize) -> usize {
+        let p = self.points[i];
+        // Walk toward the point until reaching its triangle or leaving the hull
+        let mut t = start;
+        'walk: while !self.triangles[t].contains(&self.ghost) {
+            self.seed = self.seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let offset = (self.seed >> 16) % 3;
+            for k in 0..3 {
+                let e = 3 * t + (k + offset) % 3;
+                let [u, v] = [self.triangles[t][e % 3], self.triangles[t][(e + 1) % 3]];
+                if robust_orientation(self.points[u], self.points[v], p) == Ordering::Less {
+                    t = self.halfedges[e] / 3;
+                    continue 'walk;
+                }
+            }
+            break;
+        }
+        // Remove the triangles whose circumcircles contain the point
+        let mut cavity = vec![t];
+        let mut boundary = Vec::new();
+        self.marks[t] = stamp;
+        let mut k = 0;
+        while k < cavity.len() {
+            let t = cavity[k];
+            k += 1;
+            for e in 3 * t..3 * t + 3 {
+                let twin = self.halfedges[e];
+                let other = twin / 3;
+                if self.marks[other] == stamp {
+                    continue;
+                }
+                if self.conflicts(other, p) {
+                    self.marks[other] = stamp;
+                    cavity.push(other);
+                } else {
+                    let [u, v] = [self.triangles[t][e % 3], self.triangles[t][(e + 1) % 3]];
+                    boundary.push((u, v, twin));
+                }
+            }
+        }
+        // Connect the point to each edge of the cavity's boundary
+        while cavity.len() < boundary.len() {
+            cavity.push(self.triangles.len());
+            self.triangles.push([0; 3]);
+            self.halfedges.extend_from_slice(&[NONE; 3]);
+            self.marks.push(0);
+        }
+        let mut result = cavity[0];
+        for (&t, &(u, v, twin)) in cavity.iter().zip(&boundary) {
+            self.triangles[t] = [u, v, i];
+            link(&mut self.halfedges, 3 * t, twin);
+            if u != self.ghost && v != self.ghost {
+                result = t;
+            }
+        }
+        for (&t, &(_, v, _)) in cavity.iter().zip(&boundary) {
+            let after = boundary.iter().position(|&(u, _, _)| u == v).unwrap();
+            link(&mut self.halfedges, 3 * t + 1, 3 * cavity[after] + 2);
+        }
+        result
+    }
+    /// Check if a point is inside a triangle's circumcircle
+    ///
+    /// The circumcircle of a ghost triangle is the open half-plane beyond its
+    /// hull edge, plus the edge itself.
+    fn conflicts(&self, t: usize, p: V) -> bool {
+        let triangle = self.triangles[t];
+        let [a, b, c] = triangle.map(|i| self.points.get(i).copied());
+        match (a, b, c) {
+            (Some(a), Some(b), Some(c)) => robust_in_circle(a, b, c, p) == Ordering::Greater,
+            _ => {
+                let k = triangle.iter().position(|&i| i == self.ghost).unwrap();
+                let u = self.points[triangle[(k + 1) % 3]];
+                let v = self.points[triangle[(k + 2) % 3]];
+                match robust_orientation(u, v, p) {
+                    Ordering::Greater => true,
+                    Ordering::Equal => {
+                        p.sub(u).dot(v.sub(u)) > V::Scalar::ZERO
+                            && p.sub(v).dot(u.sub(v)) > V::Scalar::ZERO
+                    }
+                    Ordering::Less => false,
+                }
+            }
+        }
+    }
+}
+
+/// Sort points along a Hilbert curve so that consecutive points are close together
+fn hilbert_order<V>(points: &[V], order: &[usize]) -> Vec<usize>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    const SIDE: usize = 1 << 16;
+    let mut order = order.to_vec();
+    let (mut min, mut max) = match order.first() {
+        Some(&i) => (points[i], points[i]),
+        None => return order,
+    };
+    for &i in &order {
+        min = min.min2(points[i]);
+        max = max.max2(points[i]);
+    }
+    let size = max.sub(min);
+    let size = size.x().maxx(size.y());
+    let size = if size.is_zero() { V::Scalar::ONE } else { size };
+    let scale = V::Scalar::from_usize(SIDE - 1) / size;
+    let mut keys: Vec<(usize, usize)> = order
+        .iter()
+        .map(|&i| {
+            let relative = points[i].sub(min);
+            let mut x = (relative.x() * scale).to_usize().min(SIDE - 1);
+            let mut y = (relative.y() * scale).to_usize().min(SIDE - 1);
+            let mut d = 0;
+            let mut s = SIDE / 2;
+            while s > 0 {
+                let (rx, ry) = ((x & s > 0) as usize, (y & s > 0) as usize);
+                d += s * s * ((3 * rx) ^ ry);
+                if ry == 0 {
+                    if rx == 1 {
+                        x = SIDE - 1 - x;
+                        y = SIDE - 1 - y;
+                    }
+                    std::mem::swap(&mut x, &mut y);
+                }
+                s /= 2;
+            }
+            (d, i)
+        })
+        .collect();
+    keys.sort_unstable();
+    order.clear();
+    order.extend(keys.into_iter().map(|(_, i)| i));
+    order
+}
+
+/// Check if two segments cross at a single point that is not an endpoint of either
+fn segments_cross<V>(a: V, b: V, c: V, d: V) -> bool
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let (abc, abd) = (robust_orientation(a, b, c), robust_orientation(a, b, d));
+    let (cda, cdb) = (robust_orientation(c, d, a), robust_orientation(c, d, b));
+    abc != Ordering::Equal
+        && abd != Ordering::Equal
+        && abc != abd
+        && cda != Ordering::Equal
+        && cdb != Ordering::Equal
+        && cda != cdb
+}
+
+#[cfg(test)]
+#[test]
+fn delaunay() {
+    use crate::{Polygon, Vector2};
+    // A grid with some jitter and a duplicate
+    let mut points = Vec::new();
+    for i in 0..6 {
+        for j in 0..6 {
+            let jitter = ((i * 7 + j * 13) % 5) as f64 * 0.05;
+            points.push([i as f64 + jitter, j as f64 - jitter]);
+        }
+    }
+    points.push(points[8]);
+    let delaunay = Delaunay::new(&points);
+    let is_delaunay = |points: &[[f64; 2]], triangles: &[[usize; 3]]| {
+        triangles.iter().all(|&[a, b, c]| {
+            let [pa, pb, pc] = [a, b, c].map(|i| points[i]);
+            robust_orientation(pa, pb, pc) == Ordering::Greater
+                && points
+                    .iter()
+                    .all(|&p| robust_in_circle(pa, pb, pc, p) != Ordering::Greater)
+        })
+    };
+    // Every triangulation of n points with h on the hull has 2n - 2 - h triangles that cover the hull
+    let covers_hull = |points: &[[f64; 2]], triangles: &[[usize; 3]]| {
+        let hull = crate::convex_hull_with(
+            points.iter().copied(),
+            crate::Winding::CounterClockwise,
+            true,
+        );
+        let area: f64 = triangles
+            .iter()
+            .map(|t| t.map(|i| points[i]).signed_area())
+            .sum();
+        triangles.len() == 2 * points.len() - 2 - hull.len()
+            && (area - hull.signed_area().abs()).abs() < 1e-9 * area
+    };
+    assert!(is_delaunay(&points, delaunay.triangles()));
+    for (i, &p) in points.iter().enumerate() {
+        let nearest = delaunay.nearest(p.add([0.01, 0.02])).unwrap();
+        assert_eq!(points[i], points[nearest]);
+    }
+    // A constrained edge across the grid
+    let constrained = delaunay.clone().with_constraints(&[[0, 35]]);
+    for &[a, b] in constrained.constraints() {
+        let triangles = constrained.triangles();
+        assert!(triangles.iter().any(|t| t.contains(&a) && t.contains(&b)));
+    }
+    assert_eq!(delaunay.triangles().len(), constrained.triangles().len());
+    // Voronoi cells tile the bounds
+    let cells = delaunay.voronoi([-1.0, -1.0, 8.0, 8.0]);
+    let area: f64 = cells
+        .iter()
+        .take(36)
+        .map(|cell| cell.signed_area().abs())
+        .sum();
+    assert!((area - 64.0).abs() < 1e-9);
+    // Points on a circle around its center, where every triangle is degenerate for a naive predicate
+    let mut circle: Vec<[f64; 2]> = (0..200)
+        .map(|i| [0.0, 1.0].rotate(i as f64 * std::f64::consts::TAU / 200.0))
+        .collect();
+    circle.push([0.0, 0.0]);
+    let wheel = Delaunay::new(&circle);
+    assert_eq!(200, wheel.triangles().len());
+    assert!(covers_hull(&circle, wheel.triangles()));
+    // A regular grid, where every square's corners are cocircular
+    let grid: Vec<[f64; 2]> = (0..400)
+        .map(|i| [(i % 20) as f64 * 0.1, (i / 20) as f64 * 0.3])
+        .collect();
+    let delaunay = Delaunay::new(&grid);
+    assert!(covers_hull(&grid, delaunay.triangles()));
+    assert!(is_delaunay(&grid, delaunay.triangles()));
+    // Random points
+    let mut seed = 1u64;
+    let mut random = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed >> 11) as f64 / (1u64 << 53) as f64
+    };
+    let scattered: Vec<[f64; 2]> = (0..300).map(|_| [random(), random()]).collect();
+    let delaunay = Delaunay::new(&scattered);
+    assert!(covers_hull(&scattered, delaunay.triangles()));
+    assert!(is_delaunay(&scattered, delaunay.triangles()));
+    // Constraints that cross earlier ones are skipped
+    let constrained = delaunay
+        .clone()
+        .with_constraints(&[[0, 1], [2, 3], [4, 5], [6, 7]]);
+    let constraints = constrained.constraints();
+    assert!(!constraints.is_empty());
+    for (i, &[a, b]) in constraints.iter().enumerate() {
+        let [pa, pb] = [a, b].map(|i| scattered[i]);
+        let triangles = constrained.triangles();
+        assert!(triangles.iter().any(|t| t.contains(&a) && t.contains(&b)));
+        for &[c, d] in &constraints[..i] {
+            assert!(!segments_cross(pa, pb, scattered[c], scattered[d]));
+        }
+    }
+    assert!(covers_hull(&scattered, constrained.triangles()));
+    // Collinear points have no triangles but still have cells
+    let line = Delaunay::new(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]);
+    assert!(line.triangles().is_empty());
+    assert_eq!(6, line.voronoi([0.0, 0.0, 2.0, 2.0])[1].len());
+}
@@ -0,0 +1,302 @@
+//! Module for the [`Ellipse`] trait
+
+use crate::{FloatingScalar, FloatingVector2, Rectangle, Scalar as _, Transform, Vector2};
+
+/// The scalar type of an [`Ellipse`]
+pub type Scalar<T> = <<T as Ellipse>::Vector as Vector2>::Scalar;
+
+/**
+Trait for manipulating rotated ellipses
+
+An ellipse is defined by its center, its radii along its own x and y axes,
+and a rotation in radians of those axes.
+
+```
+use vector2math::*;
+
+let ellipse = ([0.0, 0.0], [2.0, 1.0], 0.0);
+assert!(ellipse.contains([1.5, 0.5]));
+assert!(!ellipse.contains([1.5, 1.0]));
+assert_eq!([-2.0, -1.0, 4.0, 2.0], ellipse.bounding_rect::<f64::Rect>());
+```
+*/
+pub trait Ellipse: Copy
+where
+    Scalar<Self>: FloatingScalar,
+{
+    /// The vector type
+    type Vector: FloatingVector2;
+    /// Create a new ellipse from a center coordinate, radii, and a rotation in radians
+    fn new(center: Self::Vector, radii: Self::Vector, rotation: Scalar<Self>) -> Self;
+    /// Get the ellipse's center
+    fn center(self) -> Self::Vector;
+    /// Get the ellipse's radii along its own x and y axes
+    fn radii(self) -> Self::Vector;
+    /// Get the ellipse's rotation in radians
+    fn rotation(self) -> Scalar<Self>;
+    /// Create a new ellipse from a circle
+    fn from_circle<C>(circle: C) -> Self
+    where
+        C: crate::Circle<Vector = Self::Vector>,
+    {
+        Self::new(
+            circle.center(),
+            Self::Vector::square(circle.radius()),
+            Scalar::<Self>::ZERO,
+        )
+    }
+    /// Map this ellipse to an ellipse of another type
+    fn map_into<E>(self) -> E
+    where
+        E: Ellipse,
+        Scalar<E>: FloatingScalar + From<Scalar<Self>>,
+    {
+        E::new(
+            self.center().map_into(),
+            self.radii().map_into(),
+            Scalar::<E>::from(self.rotation()),
+        )
+    }
+    /// Map this ellipse to an ellipse of another type using a function
+    fn map_with<E, F>(self, mut f: F) -> E
+    where
+        E: Ellipse,
+        Scalar<E>: FloatingScalar,
+        F: FnMut(Scalar<Self>) -> <<E as Ellipse>::Vector as Vector2>::Scalar,
+    {
+        E::new(
+            self.center().map_with(&mut f),
+            self.radii().map_with(&mut f),
+            f(self.rotation()),
+        )
+    }
+    /// Transform the ellipse into one with a different center
+    fn with_center(self, center: Self::Vector) -> Self {
+        Self::new(center, self.radii(), self.rotation())
+    }
+    /// Transform the ellipse into one with different radii
+    fn with_radii(self, radii: Self::Vector) -> Self {
+        Self::new(self.center(), radii, self.rotation())
+    }
+    /// Transform the ellipse into one with a different rotation
+    fn with_rotation(self, rotation: Scalar<Self>) -> Self {
+        Self::new(self.center(), self.radii(), rotation)
+    }
+    /// Get the ellipse's area
+    fn area(self) -> Scalar<Self> {
+        self.radii().product().abs() * Scalar::<Self>::PI
+    }
+    /// Get an approximation of the ellipse's perimeter
+    ///
+    /// This uses Ramanujan's second approximation, which is exact for circles
+    /// and has a relative error of less than 0.0004% for eccentricities below 0.99.
+    fn perimeter(self) -> Scalar<Self> {
+        let radii = self.radii().abs();
+        let (a, b) = (radii.x(), radii.y());
+        let sum = a + b;
+        if sum.is_zero() {
+            return Scalar::<Self>::ZERO;
+        }
+        let three = Scalar::<Self>::TWO + Scalar::<Self>::ONE;
+        let four = Scalar::<Self>::TWO + Scalar::<Self>::TWO;
+        let ten = three * three + Scalar::<Self>::ONE;
+        let h = ((a - b) / sum).square();
+        Scalar::<Self>::PI
+            * sum
+            * (Scalar::<Self>::ONE + three * h / (ten + (four - three * h).sqrt()))
+    }
+    /// Get the ellipse that is this one translated by some vector
+    fn translated(self, offset: Self::Vector) -> Self {
+        self.with_center(self.center().add(offset))
+    }
+    /// Get the ellipse that is this one with scalar-scaled radii
+    fn scaled(self, scale: Scalar<Self>) -> Self {
+        self.with_radii(self.radii().mul(scale))
+    }
+    /// Check that the ellipse contains the given point. Includes the boundary.
+    fn contains(self, point: Self::Vector) -> bool {
+        to_local(self, point).div2(self.radii()).squared_mag() <= Scalar::<Self>::ONE
+    }
+    /// Check that the ellipse contains all points
+    fn contains_all<I>(self, points: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        points.into_iter().all(|point| self.contains(point))
+    }
+    /// Check that the ellipse contains any point
+    fn contains_any<I>(self, points: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        points.into_iter().any(|point| self.contains(point))
+    }
+    /// Get the smallest axis-aligned rectangle that contains the ellipse
+    fn bounding_rect<R>(self) -> R
+    where
+        R: Rectangle<Vector = Self::Vector>,
+    {
+        let (sin, cos) = (self.rotation().sin(), self.rotation().cos());
+        let (a, b) = (self.radii().x(), self.radii().y());
+        let half_size = Self::Vector::new(
+            ((a * cos).square() + (b * sin).square()).sqrt(),
+            ((a * sin).square() + (b * cos).square()).sqrt(),
+        );
+        R::new(
+            self.center().sub(half_size),
+            half_size.mul(Scalar::<Self>::TWO),
+        )
+    }
+    /// Get the point on the ellipse's boundary in the direction of the
+    /// given angle in radians from its center
+    fn point_at(self, radians: Scalar<Self>) -> Self::Vector {
+        let local = radians - self.rotation();
+        let (a, b) = (self.radii().x().abs(), self.radii().y().abs());
+        let dist = a * b / ((b * local.cos()).square() + (a * local.sin()).square()).sqrt();
+        self.center()
+            .add(Self::Vector::from_angle(radians).mul(dist))
+    }
+    /// Get the point on the ellipse's boundary closest to the given point
+    fn closest_point(self, point: Self::Vector) -> Self::Vector {
+        let local = to_local(self, point);
+        let (a, b) = (self.radii().x().abs(), self.radii().y().abs());
+        let (px, py) = (local.x().abs(), local.y().abs());
+        if a.is_zero() || b.is_zero() {
+            let clamped = Self::Vector::new(px.minn(a), py.minn(b)).mul2(local.signum());
+            return from_local(self, clamped);
+        }
+        // Iteratively refine the point's parametric direction, approximating
+        // the ellipse locally by its circle of curvature
+        let zero = Scalar::<Self>::ZERO;
+        let one = Scalar::<Self>::ONE;
+        let mut t = Self::Vector::square(one).unit();
+        for _ in 0..4 {
+            let on = Self::Vector::new(a * t.x(), b * t.y());
+            let evolute = Self::Vector::new(
+                (a.square() - b.square()) * t.x().square() * t.x() / a,
+                (b.square() - a.square()) * t.y().square() * t.y() / b,
+            );
+            let r = on.sub(evolute).mag();
+            let q = Self::Vector::new(px, py).sub(evolute);
+            let q_mag = q.mag();
+            if q_mag.is_zero() {
+                break;
+            }
+            t = q
+                .mul(r / q_mag)
+                .add(evolute)
+                .div2(Self::Vector::new(a, b))
+                .clamp2(Self::Vector::square(zero), Self::Vector::square(one))
+                .unit();
+        }
+        let closest = Self::Vector::new(a * t.x(), b * t.y()).mul2(Self::Vector::new(
+            if local.x() < zero { -one } else { one },
+            if local.y() < zero { -one } else { one },
+        ));
+        from_local(self, closest)
+    }
+    /// Get the ellipse that is the result of applying a transform to this one
+    ///
+    /// Because affine transforms map ellipses to ellipses, the result is exact.
+    fn transformed<T>(self, transform: T) -> Self
+    where
+        T: Transform<Scalar = Scalar<Self>> + Copy,
+    {
+        let zero = Scalar::<Self>::ZERO;
+        let one = Scalar::<Self>::ONE;
+        let two = Scalar::<Self>::TWO;
+        let origin = transform.apply(Self::Vector::square(zero));
+        let x_axis = transform.apply(Self::Vector::new(one, zero)).sub(origin);
+        let y_axis = transform.apply(Self::Vector::new(zero, one)).sub(origin);
+        // The linear part of the transform combined with the ellipse's own
+        // rotation and radii maps the unit circle onto the new ellipse
+        let (sin, cos) = (self.rotation().sin(), self.rotation().cos());
+        let col1 = x_axis.mul(cos).add(y_axis.mul(sin)).mul(self.radii().x());
+        let col2 = y_axis.mul(cos).sub(x_axis.mul(sin)).mul(self.radii().y());
+        // The singular value decomposition of that map gives the new radii and rotation
+        let e = (col1.x() + col2.y()) / two;
+        let f = (col1.x() - col2.y()) / two;
+        let g = (col1.y() + col2.x()) / two;
+        let h = (col1.y() - col2.x()) / two;
+        let q = (e.square() + h.square()).sqrt();
+        let r = (f.square() + g.square()).sqrt();
+        let rotation = (h.atan2(e) + g.atan2(f)) / two;
+        Self::new(
+            transform.apply(self.center()),
+            Self::Vector::new(q + r, (q - r).abs()),
+            rotation,
+        )
+    }
+}
+
+impl<S, V> Ellipse for (V, V, S)
+where
+    S: FloatingScalar,
+    V: FloatingVector2<Scalar = S>,
+{
+    type Vector = V;
+    fn new(center: Self::Vector, radii: Self::Vector, rotation: Scalar<Self>) -> Self {
+        (center, radii, rotation)
+    }
+    fn center(self) -> Self::Vector {
+        self.0
+    }
+    fn radii(self) -> Self::Vector {
+        self.1
+    }
+    fn rotation(self) -> Scalar<Self> {
+        self.2
+    }
+}
+
+/// Convert a point into an ellipse's local frame,
+/// where the ellipse is centered at the origin and axis-aligned
+fn to_local<E>(ellipse: E, point: E::Vector) -> E::Vector
+where
+    E: Ellipse,
+    Scalar<E>: FloatingScalar,
+{
+    point.sub(ellipse.center()).rotate(-ellipse.rotation())
+}
+
+/// Convert a point out of an ellipse's local frame
+fn from_local<E>(ellipse: E, point: E::Vector) -> E::Vector
+where
+    E: Ellipse,
+    Scalar<E>: FloatingScalar,
+{
+    point.rotate(ellipse.rotation()).add(ellipse.center())
+}
+
+#[cfg(test)]
+#[test]
+fn ellipse() {
+    use crate::{f64, FloatingScalar};
+    let circle = f64::Ellip::from_circle(([1.0, 2.0], 3.0));
+    assert!((circle.perimeter() - 6.0 * f64::PI).is_near_zero(10.0));
+    let ellipse = ([1.0, 2.0], [4.0, 2.0], 0.5);
+    assert!(
+        ellipse
+            .point_at(1.0)
+            .dist(ellipse.closest_point(ellipse.point_at(1.0)))
+            < 1e-9
+    );
+    // The closest point to an outside point is where the offset is normal to the boundary
+    let closest = ellipse.closest_point([7.0, -3.0]);
+    let local = to_local(ellipse, closest);
+    assert!((local.div2(ellipse.radii()).squared_mag() - 1.0).abs() < 1e-9);
+    let normal = local.div2([16.0, 4.0]).rotate(0.5);
+    assert!(normal.cross([7.0, -3.0].sub(closest)).abs() < 1e-9);
+    let transform = f64::Trans::identity()
+        .rotate(0.3)
+        .scale([2.0, 0.5])
+        .translate([1.0, -1.0]);
+    let transformed = ellipse.transformed(transform);
+    for i in 0..16 {
+        let angle = i as f64 / 16.0 * f64::TAU;
+        let local = [4.0 * angle.cos(), 2.0 * angle.sin()];
+        let point = from_local(ellipse, local).transform(transform);
+        let on = to_local(transformed, point).div2(transformed.radii());
+        assert!((on.squared_mag() - 1.0).abs() < 1e-9);
+    }
+}
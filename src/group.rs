@@ -190,7 +190,6 @@ However, because a trio does not necessarily have to be an
 actual tuple It is also implemented for `(T, T, T, T, T, T)` and
 `[T; 6]` with `Item = (T, T, T)` and `Item = [T; 3]` respectively.
 */
-
 pub trait Trio: Sized {
     /// The type of the trio's item
     type Item;
@@ -0,0 +1,454 @@
+//! Module for computing convex hulls
+
+use std::cmp::Ordering;
+
+use crate::{FillRule, FloatingScalar, FloatingVector2, Polygon, Scalar, Vector2, Winding};
+
+/**
+Get the convex hull of a set of points
+
+The hull is found with Andrew's monotone chain algorithm. It is clockwise,
+starts from the point with the lowest x and y, and excludes points that lie
+along its edges. Use [`convex_hull_with`] to choose the winding or to keep
+those points.
+
+Orientation tests are exact for integer vectors and robust for floating point ones.
+```
+use vector2math::*;
+
+let points = [[0, 0], [2, 0], [1, 1], [2, 1], [2, 2], [0, 2]];
+assert_eq!(vec![[0, 0], [2, 0], [2, 2], [0, 2]], convex_hull(points.iter().copied()));
+
+let hull = convex_hull_with(points.iter().copied(), Winding::CounterClockwise, true);
+assert_eq!(vec![[0, 0], [0, 2], [2, 2], [2, 1], [2, 0]], hull);
+```
+*/
+pub fn convex_hull<V, I>(points: I) -> Vec<V>
+where
+    V: Vector2,
+    I: IntoIterator<Item = V>,
+{
+    convex_hull_with(points, Winding::Clockwise, false)
+}
+
+/// Get the convex hull of a set of points with the given winding
+///
+/// If `keep_collinear` is `true`, points that lie along the hull's edges are included.
+/// If all of the points are collinear, they are returned in sorted order.
+pub fn convex_hull_with<V, I>(points: I, winding: Winding, keep_collinear: bool) -> Vec<V>
+where
+    V: Vector2,
+    I: IntoIterator<Item = V>,
+{
+    let mut sorted: Vec<V> = points.into_iter().collect();
+    sorted.sort_by(|a, b| {
+        a.x()
+            .partial_cmp(&b.x())
+            .unwrap_or(Ordering::Equal)
+            .then(a.y().partial_cmp(&b.y()).unwrap_or(Ordering::Equal))
+    });
+    sorted.dedup_by(|a, b| a.x() == b.x() && a.y() == b.y());
+    if sorted.len() < 3 {
+        return sorted;
+    }
+    let mut lower = half_hull(sorted.iter().copied(), keep_collinear);
+    let mut upper = half_hull(sorted.iter().rev().copied(), keep_collinear);
+    if lower.len() == sorted.len() && upper.len() == sorted.len() {
+        return sorted;
+    }
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    if winding == Winding::CounterClockwise {
+        lower[1..].reverse();
+    }
+    lower
+}
+
+/**
+A convex hull that points can be added to one at a time
+
+The hull's points are kept in the given winding order, without points that
+lie along its edges.
+```
+use vector2math::*;
+
+let mut hull = ConvexHull::default();
+assert!(hull.insert([0, 0]));
+hull.extend(vec![[4, 0], [4, 4], [0, 4]]);
+assert!(!hull.insert([2, 2]));
+assert!(hull.insert([6, 2]));
+assert_eq!(&[[0, 0], [4, 0], [6, 2], [4, 4], [0, 4]], hull.points());
+```
+*/
+#[derive(Debug, Clone)]
+pub struct ConvexHull<V> {
+    points: Vec<V>,
+    winding: Winding,
+}
+
+impl<V> ConvexHull<V>
+where
+    V: Vector2,
+{
+    /// Create a new empty hull whose points are kept in the given winding
+    pub fn new(winding: Winding) -> Self {
+        ConvexHull {
+            points: Vec::new(),
+            winding,
+        }
+    }
+    /// Get the points of the hull
+    pub fn points(&self) -> &[V] {
+        &self.points
+    }
+    /// Get the winding of the hull's points
+    pub fn winding(&self) -> Winding {
+        self.winding
+    }
+    /// Check that the hull contains a point. Includes edges.
+    pub fn contains(&self, point: V) -> bool {
+        self.points.contains_point(point, FillRule::NonZero)
+    }
+    /// Add a point to the hull
+    ///
+    /// The hull is updated in place in time linear in its number of points.
+    /// Returns `true` if the point was outside the hull and the hull changed.
+    pub fn insert(&mut self, point: V) -> bool {
+        if self.contains(point) {
+            return false;
+        }
+        let n = self.points.len();
+        let points = &self.points;
+        // The edges that the point can see are the ones it is not on the inner side of
+        let inward = if n >= 3 {
+            orientation(points[0], points[1], points[2])
+        } else {
+            Ordering::Equal
+        };
+        let visible = |i: usize| orientation(points[i % n], points[(i + 1) % n], point) != inward;
+        let start = (0..n).find(|&i| visible(i) && !visible(i + n - 1));
+        let (start, count) = match start {
+            Some(start) if inward != Ordering::Equal => (
+                start,
+                (start..start + n).take_while(|&i| visible(i)).count(),
+            ),
+            _ => {
+                // The hull is too small to have a direction
+                let points = self.points.iter().copied().chain(Some(point));
+                self.points = convex_hull_with(points, self.winding, false);
+                return true;
+            }
+        };
+        // Replace the points between the first and last visible edges with the new point
+        self.points.rotate_left(start);
+        self.points.splice(1..count, Some(point));
+        let first = (0..self.points.len())
+            .min_by(|&a, &b| {
+                let (a, b) = (self.points[a], self.points[b]);
+                a.x()
+                    .partial_cmp(&b.x())
+                    .unwrap_or(Ordering::Equal)
+                    .then(a.y().partial_cmp(&b.y()).unwrap_or(Ordering::Equal))
+            })
+            .unwrap_or(0);
+        self.points.rotate_left(first);
+        true
+    }
+}
+
+impl<V> Default for ConvexHull<V>
+where
+    V: Vector2,
+{
+    fn default() -> Self {
+        ConvexHull::new(Winding::default())
+    }
+}
+
+impl<V> Extend<V> for ConvexHull<V>
+where
+    V: Vector2,
+{
+    fn extend<I>(&mut self, points: I)
+    where
+        I: IntoIterator<Item = V>,
+    {
+        for point in points {
+            self.insert(point);
+        }
+    }
+}
+
+/// Get one half of a convex hull from points sorted along the x axis
+fn half_hull<V, I>(points: I, keep_collinear: bool) -> Vec<V>
+where
+    V: Vector2,
+    I: Iterator<Item = V>,
+{
+    let mut hull: Vec<V> = Vec::new();
+    for point in points {
+        while hull.len() >= 2 {
+            match orientation(hull[hull.len() - 2], hull[hull.len() - 1], point) {
+                Ordering::Greater => break,
+                Ordering::Equal if keep_collinear => break,
+                _ => hull.pop(),
+            };
+        }
+        hull.push(point);
+    }
+    hull
+}
+
+/// Get the orientation of the path `a -> b -> c` in a Y-up coordinate system
+///
+/// `Greater` is a left turn, `Less` is a right turn, and `Equal` means the points are collinear.
+/// This is exact for both integer and floating point vectors.
+pub(crate) fn orientation<V>(a: V, b: V, c: V) -> Ordering
+where
+    V: Vector2,
+{
+    V::Scalar::orientation([a.x(), a.y()], [b.x(), b.y()], [c.x(), c.y()])
+}
+
+/// Get the orientation of the path `a -> b -> c` in a Y-up coordinate system, exactly
+///
+/// `Greater` is a left turn, `Less` is a right turn, and `Equal` means the points are collinear.
+/// Unlike [`orientation`], this is exact for floating point vectors, as long as
+/// none of the intermediate products overflow or underflow.
+pub(crate) fn robust_orientation<V>(a: V, b: V, c: V) -> Ordering
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let left = (a.x() - c.x()) * (b.y() - c.y());
+    let right = (a.y() - c.y()) * (b.x() - c.x());
+    let det = left - right;
+    // Skip the exact computation when the error bound shows the sign is correct
+    let epsilon = V::Scalar::EPSILON / V::Scalar::TWO;
+    let three = V::Scalar::TWO + V::Scalar::ONE;
+    let error_bound =
+        (three + V::Scalar::from_usize(16) * epsilon) * epsilon * (left.abs() + right.abs());
+    if det > error_bound {
+        return Ordering::Greater;
+    }
+    if -det > error_bound {
+        return Ordering::Less;
+    }
+    // Sum the six products of the expanded determinant exactly
+    let splitter = splitter::<V::Scalar>();
+    let products = [
+        (a.x(), b.y()),
+        (b.x(), c.y()),
+        (c.x(), a.y()),
+        (-a.x(), c.y()),
+        (-b.x(), a.y()),
+        (-c.x(), b.y()),
+    ];
+    let mut expansion = Vec::with_capacity(12);
+    for &(p, q) in &products {
+        let (product, error) = two_product(p, q, splitter);
+        grow_expansion(&mut expansion, error);
+        grow_expansion(&mut expansion, product);
+    }
+    expansion_sign(&expansion)
+}
+
+/// Check where `d` is relative to the circumcircle of the counter-clockwise triangle `a, b, c`, exactly
+///
+/// `Greater` means inside the circle, `Less` means outside, and `Equal` means on it.
+/// Like [`robust_orientation`], this is exact as long as none of the
+/// intermediate products overflow or underflow.
+pub(crate) fn robust_in_circle<V>(a: V, b: V, c: V, d: V) -> Ordering
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let (a, b, c) = ([a.x(), a.y()], [b.x(), b.y()], [c.x(), c.y()]);
+    let d = [d.x(), d.y()];
+    let relative = |p: [V::Scalar; 2]| [p[0] - d[0], p[1] - d[1]];
+    let (ra, rb, rc) = (relative(a), relative(b), relative(c));
+    let lift = |p: [V::Scalar; 2]| p[0] * p[0] + p[1] * p[1];
+    let cross = |p: [V::Scalar; 2], q: [V::Scalar; 2]| (p[0] * q[1], p[1] * q[0]);
+    let (bc, bc_neg) = cross(rb, rc);
+    let (ca, ca_neg) = cross(rc, ra);
+    let (ab, ab_neg) = cross(ra, rb);
+    let det = lift(ra) * (bc - bc_neg) + lift(rb) * (ca - ca_neg) + lift(rc) * (ab - ab_neg);
+    // Skip the exact computation when the error bound shows the sign is correct
+    let permanent = (bc.abs() + bc_neg.abs()) * lift(ra)
+        + (ca.abs() + ca_neg.abs()) * lift(rb)
+        + (ab.abs() + ab_neg.abs()) * lift(rc);
+    let epsilon = V::Scalar::EPSILON / V::Scalar::TWO;
+    let error_bound =
+        (V::Scalar::from_usize(10) + V::Scalar::from_usize(96) * epsilon) * epsilon * permanent;
+    if det > error_bound {
+        return Ordering::Greater;
+    }
+    if -det > error_bound {
+        return Ordering::Less;
+    }
+    // Evaluate the determinant exactly with expansions of the differences
+    let splitter = splitter::<V::Scalar>();
+    let difference = |p: V::Scalar, q: V::Scalar| {
+        let (sum, error) = two_sum(p, -q);
+        vec![error, sum]
+    };
+    let multiply = |e: &[V::Scalar], f: &[V::Scalar]| {
+        f.iter().fold(Vec::new(), |product, &component| {
+            add_expansions(&product, &scale_expansion(e, component, splitter))
+        })
+    };
+    let exact = |p: [V::Scalar; 2]| [difference(p[0], d[0]), difference(p[1], d[1])];
+    let (ea, eb, ec) = (exact(a), exact(b), exact(c));
+    let lift =
+        |p: &[Vec<V::Scalar>; 2]| add_expansions(&multiply(&p[0], &p[0]), &multiply(&p[1], &p[1]));
+    let cross = |p: &[Vec<V::Scalar>; 2], q: &[Vec<V::Scalar>; 2]| {
+        let negative: Vec<V::Scalar> = multiply(&p[1], &q[0]).iter().map(|&n| -n).collect();
+        add_expansions(&multiply(&p[0], &q[1]), &negative)
+    };
+    let terms = [
+        multiply(&lift(&ea), &cross(&eb, &ec)),
+        multiply(&lift(&eb), &cross(&ec, &ea)),
+        multiply(&lift(&ec), &cross(&ea, &eb)),
+    ];
+    let sum = add_expansions(&add_expansions(&terms[0], &terms[1]), &terms[2]);
+    expansion_sign(&sum)
+}
+
+/// Get the sign of an expansion, which is the sign of its largest component
+fn expansion_sign<S>(expansion: &[S]) -> Ordering
+where
+    S: FloatingScalar,
+{
+    expansion
+        .iter()
+        .rev()
+        .filter_map(|component| component.partial_cmp(&S::ZERO))
+        .find(|&sign| sign != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Get the constant used to split a float into two halves that can be multiplied exactly
+fn splitter<S>() -> S
+where
+    S: FloatingScalar,
+{
+    let mut precision = 1;
+    let mut epsilon = S::ONE;
+    while epsilon > S::EPSILON {
+        epsilon = epsilon / S::TWO;
+        precision += 1;
+    }
+    let mut splitter = S::ONE;
+    for _ in 0..(precision + 1) / 2 {
+        splitter = splitter * S::TWO;
+    }
+    splitter + S::ONE
+}
+
+/// Get the sum of two floats and the rounding error of that sum
+fn two_sum<S>(a: S, b: S) -> (S, S)
+where
+    S: FloatingScalar,
+{
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+/// Get the product of two floats and the rounding error of that product
+fn two_product<S>(a: S, b: S, splitter: S) -> (S, S)
+where
+    S: FloatingScalar,
+{
+    let split = |n: S| {
+        let c = splitter * n;
+        let high = c - (c - n);
+        (high, n - high)
+    };
+    let product = a * b;
+    let (a_high, a_low) = split(a);
+    let (b_high, b_low) = split(b);
+    let error = product - a_high * b_high - a_low * b_high - a_high * b_low;
+    (product, a_low * b_low - error)
+}
+
+/// Add a float to an expansion of nonoverlapping floats in increasing order of magnitude
+fn grow_expansion<S>(expansion: &mut Vec<S>, n: S)
+where
+    S: FloatingScalar,
+{
+    let mut carry = n;
+    for component in expansion.iter_mut() {
+        let (sum, error) = two_sum(carry, *component);
+        *component = error;
+        carry = sum;
+    }
+    expansion.push(carry);
+}
+
+/// Add two expansions, dropping zero components
+fn add_expansions<S>(e: &[S], f: &[S]) -> Vec<S>
+where
+    S: FloatingScalar,
+{
+    let mut sum = e.to_vec();
+    for &component in f {
+        grow_expansion(&mut sum, component);
+    }
+    sum.retain(|&component| component != S::ZERO);
+    sum
+}
+
+/// Multiply an expansion by a float, dropping zero components
+fn scale_expansion<S>(e: &[S], b: S, splitter: S) -> Vec<S>
+where
+    S: FloatingScalar,
+{
+    let mut product = Vec::with_capacity(e.len() * 2);
+    for &component in e {
+        let (high, low) = two_product(component, b, splitter);
+        grow_expansion(&mut product, low);
+        grow_expansion(&mut product, high);
+    }
+    product.retain(|&component| component != S::ZERO);
+    product
+}
+
+#[cfg(test)]
+#[test]
+fn hull() {
+    // These points are exactly collinear, but a naive determinant says otherwise
+    let [a, b, c] = [[0.1, 0.1 * 3.0], [0.2, 0.2 * 3.0], [0.8, 0.8 * 3.0]];
+    let naive = (b.x() - a.x()) * (c.y() - a.y()) - (b.y() - a.y()) * (c.x() - a.x());
+    assert_ne!(0.0, naive);
+    assert_eq!(Ordering::Equal, robust_orientation(a, b, c));
+    assert_eq!(Ordering::Equal, orientation(a, b, c));
+    // The fourth corner of a rectangle is exactly on the circle through the other three
+    let [a, b, c, d] = [[0.1, 0.3], [0.7, 0.3], [0.7, 1.9], [0.1, 1.9]];
+    assert_eq!(Ordering::Equal, robust_in_circle(a, b, c, d));
+    assert_eq!(Ordering::Greater, robust_in_circle(a, b, c, [0.4, 1.0]));
+    assert_eq!(Ordering::Less, robust_in_circle(a, b, c, [0.1, 1.9000001]));
+    let square = [[0u8, 0], [3, 0], [3, 3], [0, 3], [1, 0], [3, 2], [1, 1]];
+    let hull = convex_hull(square.iter().copied());
+    assert_eq!(vec![[0, 0], [3, 0], [3, 3], [0, 3]], hull);
+    let collinear = convex_hull_with(square.iter().copied(), Winding::Clockwise, true);
+    assert_eq!(
+        vec![[0, 0], [1, 0], [3, 0], [3, 2], [3, 3], [0, 3]],
+        collinear
+    );
+    // Building a hull incrementally gives the same result as all at once
+    let points: Vec<[i32; 2]> = (0..200)
+        .map(|i| [(i * 37) % 23 + i / 7, (i * 53) % 31 - i / 5])
+        .collect();
+    for &winding in &[Winding::Clockwise, Winding::CounterClockwise] {
+        let mut incremental = ConvexHull::new(winding);
+        for (i, &point) in points.iter().enumerate() {
+            incremental.insert(point);
+            let batch = convex_hull_with(points[..=i].iter().copied(), winding, false);
+            assert_eq!(batch, incremental.points());
+        }
+        assert!(points.iter().all(|&p| incremental.contains(p)));
+    }
+}
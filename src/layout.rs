@@ -0,0 +1,401 @@
+//! Module for laying out rectangles
+
+use crate::{Rectangle, Scalar, Vector2};
+
+/// The axis along which a rectangle is divided
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Divide into pieces side by side, from left to right
+    #[default]
+    Horizontal,
+    /// Divide into pieces stacked from top to bottom
+    Vertical,
+}
+
+/// The placement of something along one axis of a container
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Align {
+    /// At the left or top
+    #[default]
+    Start,
+    /// In the middle
+    Center,
+    /// At the right or bottom
+    End,
+}
+
+/// One of the nine points of a rectangle that it can be positioned by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Anchor {
+    /// The top-left corner
+    #[default]
+    TopLeft,
+    /// The middle of the top edge
+    Top,
+    /// The top-right corner
+    TopRight,
+    /// The middle of the left edge
+    Left,
+    /// The center
+    Center,
+    /// The middle of the right edge
+    Right,
+    /// The bottom-left corner
+    BottomLeft,
+    /// The middle of the bottom edge
+    Bottom,
+    /// The bottom-right corner
+    BottomRight,
+}
+
+impl Anchor {
+    /// Get the anchor with the given horizontal and vertical alignment
+    pub fn new(horizontal: Align, vertical: Align) -> Self {
+        match (horizontal, vertical) {
+            (Align::Start, Align::Start) => Anchor::TopLeft,
+            (Align::Center, Align::Start) => Anchor::Top,
+            (Align::End, Align::Start) => Anchor::TopRight,
+            (Align::Start, Align::Center) => Anchor::Left,
+            (Align::Center, Align::Center) => Anchor::Center,
+            (Align::End, Align::Center) => Anchor::Right,
+            (Align::Start, Align::End) => Anchor::BottomLeft,
+            (Align::Center, Align::End) => Anchor::Bottom,
+            (Align::End, Align::End) => Anchor::BottomRight,
+        }
+    }
+    /// Get the horizontal alignment of the anchor
+    pub fn horizontal(self) -> Align {
+        match self {
+            Anchor::TopLeft | Anchor::Left | Anchor::BottomLeft => Align::Start,
+            Anchor::Top | Anchor::Center | Anchor::Bottom => Align::Center,
+            Anchor::TopRight | Anchor::Right | Anchor::BottomRight => Align::End,
+        }
+    }
+    /// Get the vertical alignment of the anchor
+    pub fn vertical(self) -> Align {
+        match self {
+            Anchor::TopLeft | Anchor::Top | Anchor::TopRight => Align::Start,
+            Anchor::Left | Anchor::Center | Anchor::Right => Align::Center,
+            Anchor::BottomLeft | Anchor::Bottom | Anchor::BottomRight => Align::End,
+        }
+    }
+}
+
+impl Align {
+    /// Get the start of something of a size aligned within a span
+    ///
+    /// If the result would be negative, unsigned scalars underflow.
+    pub(crate) fn place<S>(self, start: S, length: S, size: S) -> S
+    where
+        S: Scalar,
+    {
+        // Centering never subtracts more than the result, so it only underflows when
+        // the result is negative, like the other alignments
+        match self {
+            Align::Start => start,
+            Align::Center if length >= size => start + (length - size) / S::TWO,
+            Align::Center => start - (size - length) / S::TWO,
+            Align::End => start + length - size,
+        }
+    }
+    /// Get the point on a span given by this alignment
+    pub(crate) fn point<S>(self, start: S, length: S) -> S
+    where
+        S: Scalar,
+    {
+        match self {
+            Align::Start => start,
+            Align::Center => start + length / S::TWO,
+            Align::End => start + length,
+        }
+    }
+}
+
+/// A rule for the size of one piece of a [`Layout`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Constraint<S> {
+    /// Exactly the given size
+    Fixed(S),
+    /// The given percentage of the space, not counting gaps
+    Percentage(S),
+    /// At least the given size
+    ///
+    /// If there are no [`Constraint::Fill`] pieces, these share any unused space.
+    Min(S),
+    /// At most the given size
+    ///
+    /// These are the first to shrink if there is not enough space.
+    Max(S),
+    /// A share of the unused space in proportion to the given weight
+    Fill(S),
+}
+
+/**
+A description of how to divide a rectangle into pieces along one axis
+
+This works like the layout engines of terminal UI libraries. Each piece
+is given its minimum size, and any space that is left over goes to the
+[`Constraint::Fill`] pieces, or to the [`Constraint::Min`] pieces if there
+are none. If there is not enough space, [`Constraint::Max`] pieces shrink
+first, and then the last pieces are cut short.
+
+Sizes are rounded toward zero for integer rectangles, but
+fill pieces always use all of the unused space.
+```
+use vector2math::*;
+
+let layout = Layout::vertical(vec![
+    Constraint::Fixed(1),
+    Constraint::Fill(1),
+    Constraint::Percentage(20),
+])
+.with_gap(1);
+let pieces = layout.split([0, 0, 40, 22]);
+assert_eq!(vec![[0, 0, 40, 1], [0, 2, 40, 15], [0, 18, 40, 4]], pieces);
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout<S> {
+    /// The axis along which to divide
+    pub direction: Direction,
+    /// The constraint for each piece
+    pub constraints: Vec<Constraint<S>>,
+    /// The space between pieces
+    pub gap: S,
+}
+
+impl<S> Layout<S>
+where
+    S: Scalar,
+{
+    /// Create a new layout with no gaps
+    pub fn new(direction: Direction, constraints: Vec<Constraint<S>>) -> Self {
+        Layout {
+            direction,
+            constraints,
+            gap: S::ZERO,
+        }
+    }
+    /// Create a new layout of pieces side by side
+    pub fn horizontal(constraints: Vec<Constraint<S>>) -> Self {
+        Layout::new(Direction::Horizontal, constraints)
+    }
+    /// Create a new layout of pieces stacked vertically
+    pub fn vertical(constraints: Vec<Constraint<S>>) -> Self {
+        Layout::new(Direction::Vertical, constraints)
+    }
+    /// Transform the layout into one with a different gap between pieces
+    pub fn with_gap(self, gap: S) -> Self {
+        Layout { gap, ..self }
+    }
+    /// Divide a rectangle into one piece for each constraint
+    pub fn split<R>(&self, rect: R) -> Vec<R>
+    where
+        R: Rectangle,
+        R::Vector: Vector2<Scalar = S>,
+    {
+        let horizontal = self.direction == Direction::Horizontal;
+        let (length, breadth) = if horizontal {
+            (rect.abs_width(), rect.abs_height())
+        } else {
+            (rect.abs_height(), rect.abs_width())
+        };
+        let gap = self.gap.maxx(S::ZERO);
+        let mut start = if horizontal {
+            rect.abs_left()
+        } else {
+            rect.abs_top()
+        };
+        let mut pieces = Vec::with_capacity(self.constraints.len());
+        for size in self.sizes(length) {
+            let piece = if horizontal {
+                R::new(
+                    R::Vector::new(start, rect.abs_top()),
+                    R::Vector::new(size, breadth),
+                )
+            } else {
+                R::new(
+                    R::Vector::new(rect.abs_left(), start),
+                    R::Vector::new(breadth, size),
+                )
+            };
+            pieces.push(piece);
+            start = start + size + gap;
+        }
+        pieces
+    }
+    /// Get the size of each piece along a length
+    fn sizes(&self, length: S) -> Vec<S> {
+        let zero = S::ZERO;
+        let gap = self.gap.maxx(zero);
+        let gaps = (1..self.constraints.len()).fold(zero, |sum, _| sum + gap);
+        let available = if length > gaps { length - gaps } else { zero };
+        let ten = S::TWO * S::TWO * S::TWO + S::TWO;
+        let mut sizes: Vec<S> = self
+            .constraints
+            .iter()
+            .map(|constraint| match *constraint {
+                Constraint::Fixed(size) | Constraint::Min(size) | Constraint::Max(size) => {
+                    size.maxx(zero)
+                }
+                Constraint::Percentage(percent) => available * percent.maxx(zero) / (ten * ten),
+                Constraint::Fill(_) => zero,
+            })
+            .collect();
+        let total = sizes.iter().fold(zero, |sum, &size| sum + size);
+        if total > available {
+            let mut excess = total - available;
+            let is_max = |i: &usize| matches!(self.constraints[*i], Constraint::Max(_));
+            let maxes = (0..sizes.len()).rev().filter(is_max);
+            for i in maxes.chain((0..sizes.len()).rev()) {
+                let cut = sizes[i].minn(excess);
+                sizes[i] = sizes[i] - cut;
+                excess = excess - cut;
+            }
+            return sizes;
+        }
+        // Share the unused space by weight
+        let mut growing: Vec<(usize, S)> = self
+            .constraints
+            .iter()
+            .enumerate()
+            .filter_map(|(i, constraint)| match *constraint {
+                Constraint::Fill(weight) => Some((i, weight.maxx(zero))),
+                _ => None,
+            })
+            .collect();
+        if growing.is_empty() {
+            growing = self
+                .constraints
+                .iter()
+                .enumerate()
+                .filter(|(_, constraint)| matches!(constraint, Constraint::Min(_)))
+                .map(|(i, _)| (i, S::ONE))
+                .collect();
+        }
+        let mut weight = growing.iter().fold(zero, |sum, &(_, weight)| sum + weight);
+        if weight == zero {
+            for grow in &mut growing {
+                grow.1 = S::ONE;
+                weight = weight + S::ONE;
+            }
+        }
+        // Giving each piece its share of the space so far makes
+        // rounded shares add up to the whole space
+        let extra = available - total;
+        let (mut cumulative, mut given) = (zero, zero);
+        for (i, share) in growing {
+            cumulative = cumulative + share;
+            let end = extra * cumulative / weight;
+            sizes[i] = sizes[i] + (end - given);
+            given = end;
+        }
+        sizes
+    }
+}
+
+/// An iterator over the cells of a rectangle divided into a grid
+///
+/// This is created with [`Rectangle::grid`].
+#[derive(Debug, Clone)]
+pub struct Grid<R> {
+    rows: Vec<R>,
+    columns: Vec<R>,
+    index: usize,
+}
+
+impl<R> Grid<R> {
+    pub(crate) fn new(rows: Vec<R>, columns: Vec<R>) -> Self {
+        Grid {
+            rows,
+            columns,
+            index: 0,
+        }
+    }
+}
+
+impl<R> Iterator for Grid<R>
+where
+    R: Rectangle,
+{
+    type Item = R;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.columns.is_empty() || self.index >= self.rows.len() * self.columns.len() {
+            return None;
+        }
+        let (row, column) = (
+            self.rows[self.index / self.columns.len()],
+            self.columns[self.index % self.columns.len()],
+        );
+        self.index += 1;
+        Some(R::new(
+            R::Vector::new(column.left(), row.top()),
+            R::Vector::new(column.width(), row.height()),
+        ))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.rows.len() * self.columns.len() - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<R> ExactSizeIterator for Grid<R> where R: Rectangle {}
+
+#[cfg(test)]
+#[test]
+fn layout() {
+    let rect = [0, 0, 100, 10];
+    // Unused space goes to fill pieces by weight
+    let pieces = Layout::horizontal(vec![
+        Constraint::Fixed(10),
+        Constraint::Fill(1),
+        Constraint::Fill(2),
+        Constraint::Max(20),
+    ])
+    .split(rect);
+    assert_eq!(
+        vec![
+            [0, 0, 10, 10],
+            [10, 0, 23, 10],
+            [33, 0, 47, 10],
+            [80, 0, 20, 10]
+        ],
+        pieces
+    );
+    // Without fill pieces, min pieces grow
+    let pieces = Layout::horizontal(vec![Constraint::Min(10), Constraint::Percentage(50)])
+        .with_gap(4)
+        .split(rect);
+    assert_eq!(vec![[0, 0, 48, 10], [52, 0, 48, 10]], pieces);
+    // Max pieces shrink first, then the last pieces are cut short
+    let pieces = Layout::vertical(vec![
+        Constraint::Fixed(4),
+        Constraint::Max(4),
+        Constraint::Fixed(4),
+        Constraint::Fixed(4),
+    ])
+    .split(rect);
+    let heights: Vec<i32> = pieces.iter().map(|piece| piece.height()).collect();
+    assert_eq!(vec![4, 0, 4, 2], heights);
+    // Floats and negative sizes
+    let pieces = Layout::vertical(vec![Constraint::Fill(1.0); 3])
+        .with_gap(0.5)
+        .split([0.0, 4.0, 2.0, -4.0]);
+    assert_eq!(
+        vec![
+            [0.0, 0.0, 2.0, 1.0],
+            [0.0, 1.5, 2.0, 1.0],
+            [0.0, 3.0, 2.0, 1.0]
+        ],
+        pieces
+    );
+    // Integer splits cover the whole rectangle
+    let cells: Vec<[u32; 4]> = [0, 0, 10, 7].grid(3, 3, [1, 1]).collect();
+    assert_eq!(9, cells.len());
+    assert_eq!([0, 0, 2, 1], cells[0]);
+    assert_eq!([7, 5, 3, 2], cells[8]);
+    assert_eq!(
+        ([0, 0, 3, 7], [3, 0, 7, 7]),
+        [0, 0, 10, 7].split_h_ratio(1, 2)
+    );
+}
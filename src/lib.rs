@@ -78,7 +78,7 @@ Many types can be used to define axis-aligned rectangles:
 * `[T; 4]`
 * `(T, T, T, T)`
 * Any type that implements [`Pair`] where the associated
-[`Pair::Item`] type implements [`Vector2`].
+  [`Pair::Item`] type implements [`Vector2`].
 ```
 use vector2math::*;
 
@@ -102,7 +102,7 @@ A few types can be used to define circles:
 * `([T; 2], T)`
 * `((T, T), T)`
 * Any pair of types where the first implements [`FloatingVector2`]
-and the second is the vector's [`Vector2::Scalar`] type.
+  and the second is the vector's [`Vector2::Scalar`] type.
 ```
 use vector2math::*;
 use std::f64;
@@ -112,6 +112,136 @@ assert!((circle.circumference() - 25.132_741_228_718_345).abs() < f64::EPSILON);
 assert!((circle.area() - 50.265_482_457_436_69).abs() < f64::EPSILON);
 assert!(circle.contains([0.0, 1.0]));
 assert!(!circle.contains([5.0, 6.0]));
+
+let bounds: ([f64; 2], f64) = Circle::bounding(vec![[0.0, 0.0], [4.0, 0.0], [2.0, 1.0]]).unwrap();
+assert_eq!(([2.0, 0.0], 2.0), bounds);
+```
+
+# Arcs, Sectors, and Annuli
+
+Circle types can be combined with their scalar type to define other round shapes:
+* An [`Arc`] is a circle, a start angle, and a sweep
+* A [`Sector`] is an arc and an inner radius
+* An [`Annulus`] is a circle and an inner radius
+```
+use vector2math::*;
+
+let progress = f64::Sect::new_annular([0.0, 0.0], 4.0, 5.0, f64::TAU / 4.0, -f64::TAU * 0.3);
+let bounds: f64::Rect = progress.bounding_rect();
+assert!(progress.contains([4.5, 0.0]));
+assert!(bounds.contains([4.5, 0.0]));
+assert!(!progress.contains([-4.5, 0.0]));
+let outline = progress.outline(0.01);
+assert!(bounds.outer_margin(1e-9).contains_all(outline));
+```
+
+# Ellipses
+
+Rotated ellipses can be defined with:
+* `([T; 2], [T; 2], T)`
+* `((T, T), (T, T), T)`
+* Any triple of types where the first two implement [`FloatingVector2`]
+  and the third is the vector's [`Vector2::Scalar`] type.
+
+These are the center, the radii, and the rotation in radians.
+Ellipses are closed under [`Transform`]s.
+```
+use vector2math::*;
+
+let circle = ([0.0, 0.0], 1.0);
+let transform = f64::Trans::identity().scale([2.0, 1.0]).rotate(f64::TAU / 4.0);
+let ellipse = f64::Ellip::from_circle(circle).transformed(transform);
+assert!(ellipse.contains([0.0, 1.9]));
+assert!(!ellipse.contains([1.1, 0.0]));
+```
+
+# Oriented Rectangles
+
+Any pair of a rectangle type and its scalar type can be used to define
+a rectangle that is rotated about its center by some number of radians.
+```
+use vector2math::*;
+
+let a = f32::ORect::new([0.0, 0.0], [2.0, 1.0], f32::TAU / 8.0);
+let b = f32::ORect::from_rect([2.5, -1.0, 2.0, 2.0]);
+assert!(a.contains([1.0, 1.0]));
+assert!(!a.intersects(b));
+assert!(a.intersects(b.translated([-0.5, 0.0])));
+```
+
+# Rounded Rectangles and Capsules
+
+A rectangle type paired with an array of four of its scalar type defines
+a rectangle with rounded corners. A circle type paired with its vector type
+defines a capsule, where the circle is swept from its center to the vector.
+```
+use vector2math::*;
+
+let button = f32::RRect::uniform([0.0, 0.0, 10.0, 4.0], 1.0);
+assert!(button.contains([5.0, 2.0]));
+assert!(!button.contains([0.0, 0.0]));
+
+let collider = f32::Caps::new([0.0, 0.0], [0.0, 2.0], 0.5);
+assert!(collider.contains([0.0, 2.5]));
+assert_eq!(1.5, collider.signed_distance([2.0, 1.0]));
+```
+
+# Triangles
+
+Any type that implements [`Trio`] where the [`Trio::Item`] type implements
+[`FloatingVector2`] can be used to define a triangle. This includes
+`[[T; 2]; 3]`, `([T; 2], [T; 2], [T; 2])`, `[T; 6]`, and `(T, T, T, T, T, T)`.
+```
+use vector2math::*;
+
+let tri = [[0.0, 0.0], [2.0, 0.0], [0.0, 2.0]];
+assert_eq!(2.0, tri.area());
+assert_eq!([2.0 / 3.0; 2], tri.centroid());
+assert!(tri.contains([0.5, 0.5]));
+assert_eq!([1.0, 1.0], tri.closest_point([2.0, 2.0]));
+```
+
+# Bézier Curves
+
+The same types that define triangles also define quadratic Bézier curves,
+where the three items are the control points. Arrays and tuples of four vectors
+define cubic Bézier curves.
+```
+use vector2math::*;
+
+let quad = [[0.0, 0.0], [1.0, 2.0], [2.0, 0.0]];
+assert_eq!([1.0, 1.0], quad.eval(0.5));
+
+let cubic = [[0.0, 0.0], [0.0, 2.0], [2.0, 2.0], [2.0, 0.0]];
+let bounds: f64::Rect = cubic.bounding_rect();
+assert_eq!(1.5, bounds.height());
+assert!(cubic.flatten(0.01).len() > 2);
+```
+
+# Polygons
+
+Slices of any vector type can be used as polygons. Points can be tested
+against them with either [`FillRule`].
+```
+use vector2math::*;
+
+let star = [[0, 3], [6, 3], [1, 0], [3, 5], [5, 0]];
+assert!(star.contains_point([3, 2], FillRule::NonZero));
+assert!(!star.contains_point([3, 2], FillRule::EvenOdd));
+```
+
+# Paths
+
+The [`Path`] type is a sequence of lines, curves, and arcs that can be
+parsed from and serialized to SVG path data.
+```
+use vector2math::*;
+
+let icon: Path<f64::Vec2> = "M0 0h4v4h-4z".parse().unwrap();
+let bounds: f64::Rect = icon.bounding_rect().unwrap();
+assert_eq!([0.0, 0.0, 4.0, 4.0], bounds);
+let moved = icon.transformed(f64::Trans::new_translate([1.0, 2.0]));
+assert_eq!("M1 2H5V6H1Z", moved.to_string());
 ```
 
 # Mapping
@@ -218,16 +348,61 @@ assert_eq!(6.0, rect.bottom());
 #[cfg_attr(feature = "simd", doc(cfg(feature = "simd")))]
 pub mod simd;
 
+pub mod annulus;
+pub use annulus::Annulus;
+pub mod arc;
+pub use arc::Arc;
+pub mod boolean;
+pub use boolean::{boolean, BooleanOp};
+pub mod capsule;
+pub use capsule::Capsule;
 pub mod circle;
 pub use circle::Circle;
+pub mod cubic_bezier;
+pub use cubic_bezier::CubicBezier;
+pub mod delaunay;
+pub use delaunay::Delaunay;
+pub mod ellipse;
+pub use ellipse::Ellipse;
 mod group;
 pub use group::*;
+pub mod hull;
+pub use hull::{convex_hull, convex_hull_with, ConvexHull};
+pub mod layout;
+pub use layout::{Align, Anchor, Constraint, Direction, Grid, Layout};
+pub mod offset;
+pub use offset::{offset_polygon, offset_polyline};
+pub mod oriented_rect;
+pub use oriented_rect::OrientedRect;
+pub mod pack;
+pub use pack::{BinGrowth, PackAlgorithm, Packer, Packing};
+pub mod path;
+pub use path::{ParsePathError, Path, PathSegment};
+pub mod polygon;
+pub use polygon::{FillRule, Polygon, Winding};
+pub mod quad_bezier;
+pub use quad_bezier::QuadBezier;
 pub mod rectangle;
 pub use rectangle::Rectangle;
+pub mod region;
+pub use region::Region;
+pub mod rounded_rect;
+pub use rounded_rect::RoundedRect;
+pub mod sector;
+pub use sector::Sector;
+pub mod simplify;
+pub use simplify::Simplification;
+pub mod spline;
+pub use spline::{BSpline, CatmullRom, Hermite, Spline};
+pub mod stroke;
+pub use stroke::{LineCap, LineJoin, Stroke};
 mod scalar;
 pub use scalar::*;
 mod transform;
 pub use transform::*;
+pub mod triangle;
+pub use triangle::Triangle;
+mod triangulate;
 
 macro_rules! int_mod {
     ($T:ident) => {
@@ -268,6 +443,26 @@ macro_rules! float_mod {
             pub type Rect = [Dim; 4];
             /// A standard circle type
             pub type Circ = (Vec2, Dim);
+            /// A standard ellipse type
+            pub type Ellip = (Vec2, Vec2, Dim);
+            /// A standard oriented rectangle type
+            pub type ORect = (Rect, Dim);
+            /// A standard rounded rectangle type
+            pub type RRect = (Rect, [Dim; 4]);
+            /// A standard capsule type
+            pub type Caps = (Circ, Vec2);
+            /// A standard arc type
+            pub type Arc = (Circ, Dim, Dim);
+            /// A standard sector type
+            pub type Sect = (Arc, Dim);
+            /// A standard annulus type
+            pub type Annul = (Circ, Dim);
+            /// A standard triangle type
+            pub type Tri = [Vec2; 3];
+            /// A standard quadratic Bézier curve type
+            pub type QuadBez = [Vec2; 3];
+            /// A standard cubic Bézier curve type
+            pub type CubicBez = [Vec2; 4];
             /// A standard transform type
             pub type Trans = [[Dim; 3]; 2];
         }
@@ -279,9 +474,21 @@ float_mod!(f64);
 
 use std::ops::Neg;
 
+pub use Annulus as _;
+pub use Arc as _;
+pub use Capsule as _;
 pub use Circle as _;
+pub use CubicBezier as _;
+pub use Ellipse as _;
+pub use OrientedRect as _;
+pub use Polygon as _;
+pub use QuadBezier as _;
 pub use Rectangle as _;
+pub use RoundedRect as _;
+pub use Sector as _;
+pub use Spline as _;
 pub use Transform as _;
+pub use Triangle as _;
 
 /// Trait for manipulating 2D vectors
 pub trait Vector2: Copy {
@@ -432,6 +639,76 @@ pub trait Vector2: Copy {
         let sum = self.mul2(other);
         sum.x() + sum.y()
     }
+    /// Get the 2D cross product (perp-dot product) of this vector and another
+    ///
+    /// This is the z component of the 3D cross product of the two vectors
+    /// extended with a z component of zero.
+    fn cross(self, other: Self) -> Self::Scalar {
+        self.x() * other.y() - self.y() * other.x()
+    }
+    /// Get the vector rotated a quarter turn in the positive angle direction
+    ///
+    /// For `[x, y]`, this is `[-y, x]`.
+    fn perp(self) -> Self
+    where
+        Self::Scalar: Neg<Output = Self::Scalar>,
+    {
+        Self::new(-self.y(), self.x())
+    }
+    /// Get the vector rotated a quarter turn in the negative angle direction
+    ///
+    /// For `[x, y]`, this is `[y, -x]`.
+    fn perp_cw(self) -> Self
+    where
+        Self::Scalar: Neg<Output = Self::Scalar>,
+    {
+        Self::new(self.y(), -self.x())
+    }
+    /// Reflect this vector across a surface with the given normal
+    ///
+    /// The normal is expected to be a unit vector.
+    fn reflect(self, normal: Self) -> Self {
+        self.sub(normal.mul(Self::Scalar::TWO * self.dot(normal)))
+    }
+    /// Get the vector with the absolute value of each component
+    fn abs(self) -> Self {
+        self.map_dims(Scalar::abs)
+    }
+    /// Get the vector with the sign of each component
+    ///
+    /// Each component is `1` if positive, `-1` if negative, and `0` if zero.
+    fn signum(self) -> Self {
+        self.map_dims(|d| {
+            if d > Self::Scalar::ZERO {
+                Self::Scalar::ONE
+            } else if d < Self::Scalar::ZERO {
+                Self::Scalar::ZERO - Self::Scalar::ONE
+            } else {
+                Self::Scalar::ZERO
+            }
+        })
+    }
+    /// Get the component-wise minimum of this vector and another
+    fn min2(self, other: Self) -> Self {
+        Self::new(self.x().minn(other.x()), self.y().minn(other.y()))
+    }
+    /// Get the component-wise maximum of this vector and another
+    fn max2(self, other: Self) -> Self {
+        Self::new(self.x().maxx(other.x()), self.y().maxx(other.y()))
+    }
+    /// Clamp each component of this vector between the corresponding
+    /// components of two others
+    fn clamp2(self, min: Self, max: Self) -> Self {
+        self.max2(min).min2(max)
+    }
+    /// Get the sum of the vector's components
+    fn sum(self) -> Self::Scalar {
+        self.x() + self.y()
+    }
+    /// Get the product of the vector's components
+    fn product(self) -> Self::Scalar {
+        self.x() * self.y()
+    }
 }
 
 impl<P> Vector2 for P
@@ -463,6 +740,60 @@ where
     fn from_angle(radians: Self::Scalar) -> Self {
         Self::new(radians.cos(), radians.sin())
     }
+    /// Create a new vector from polar coordinates
+    fn from_polar(radius: Self::Scalar, radians: Self::Scalar) -> Self {
+        Self::from_angle(radians).mul(radius)
+    }
+    /// Get the polar coordinates of this vector as `(radius, radians)`
+    ///
+    /// The angle is bounded between -π and π.
+    fn to_polar(self) -> (Self::Scalar, Self::Scalar) {
+        (self.mag(), self.atan())
+    }
+    /// Create a new vector from log-polar coordinates
+    fn from_log_polar(log_radius: Self::Scalar, radians: Self::Scalar) -> Self {
+        Self::from_polar(log_radius.exp(), radians)
+    }
+    /// Get the log-polar coordinates of this vector as `(log_radius, radians)`
+    ///
+    /// The log radius of the zero vector is negative infinity.
+    fn to_log_polar(self) -> (Self::Scalar, Self::Scalar) {
+        (self.mag().ln(), self.atan())
+    }
+    /// Create a new vector from barycentric coordinates relative to a triangle
+    fn from_barycentric([u, v, w]: [Self::Scalar; 3], a: Self, b: Self, c: Self) -> Self {
+        a.mul(u).add(b.mul(v)).add(c.mul(w))
+    }
+    /// Get the barycentric coordinates of this vector relative to a triangle
+    ///
+    /// The coordinates are the weights of `a`, `b`, and `c` respectively and sum to 1.
+    /// If the triangle is degenerate, the coordinates are not finite.
+    fn to_barycentric(self, a: Self, b: Self, c: Self) -> [Self::Scalar; 3] {
+        let ab = b.sub(a);
+        let ac = c.sub(a);
+        let ap = self.sub(a);
+        let denom = ab.cross(ac);
+        let v = ap.cross(ac) / denom;
+        let w = ab.cross(ap) / denom;
+        [Self::Scalar::ONE - v - w, v, w]
+    }
+    /// Create a new vector from coordinates local to a frame
+    ///
+    /// The frame is defined by an origin and two basis vectors,
+    /// which do not need to be orthogonal or unit length.
+    fn from_local(local: Self, origin: Self, x_axis: Self, y_axis: Self) -> Self {
+        origin.add(x_axis.mul(local.x())).add(y_axis.mul(local.y()))
+    }
+    /// Get the coordinates of this vector local to a frame
+    ///
+    /// The frame is defined by an origin and two basis vectors,
+    /// which do not need to be orthogonal or unit length.
+    /// If the basis vectors are parallel, the coordinates are not finite.
+    fn to_local(self, origin: Self, x_axis: Self, y_axis: Self) -> Self {
+        let offset = self.sub(origin);
+        let det = x_axis.cross(y_axis);
+        Self::new(offset.cross(y_axis) / det, x_axis.cross(offset) / det)
+    }
     /// Get the distance between this vector and another
     #[inline(always)]
     fn dist(self, to: Self) -> Self::Scalar {
@@ -536,6 +867,63 @@ where
             other.unit().mul(self.dot(other) / mag)
         }
     }
+    /// Get the component of this vector perpendicular to another
+    ///
+    /// This is the complement of [`FloatingVector2::project`], so
+    /// `v.project(w).add(v.reject(w))` is equal to `v`.
+    fn reject(self, other: Self) -> Self {
+        self.sub(self.project(other))
+    }
+    /// Get the signed angle in radians from this vector to another
+    ///
+    /// The result is bounded between -π and π and is positive if
+    /// `other` is in the positive angle direction from this vector.
+    fn angle_between(self, other: Self) -> Self::Scalar {
+        self.cross(other).atan2(self.dot(other))
+    }
+    /// Get this vector with the same direction but a different magnitude
+    fn with_mag(self, mag: Self::Scalar) -> Self {
+        self.unit().mul(mag)
+    }
+    /// Get this vector with its magnitude clamped between a minimum and maximum
+    fn clamp_mag(self, min: Self::Scalar, max: Self::Scalar) -> Self {
+        let mag = self.mag();
+        if mag < min {
+            self.with_mag(min)
+        } else if mag > max {
+            self.with_mag(max)
+        } else {
+            self
+        }
+    }
+    /// Get the vector with each component rounded down
+    fn floor(self) -> Self {
+        self.map_dims(FloatingScalar::floor)
+    }
+    /// Get the vector with each component rounded up
+    fn ceil(self) -> Self {
+        self.map_dims(FloatingScalar::ceil)
+    }
+    /// Get the vector with each component rounded to the nearest integer
+    fn round(self) -> Self {
+        self.map_dims(FloatingScalar::round)
+    }
+    /// Spherical linear interpolate the vector with another
+    ///
+    /// The direction is rotated at a constant angular rate along the
+    /// shorter arc, while the magnitude is linearly interpolated.
+    fn slerp(self, other: Self, t: Self::Scalar) -> Self {
+        let mag = self.mag().lerp(other.mag(), t);
+        let angle = self.angle_between(other) * t;
+        self.unit().rotate(angle).mul(mag)
+    }
+    /// Normalized linear interpolate the vector with another
+    ///
+    /// The result is the unit vector in the direction of the linear interpolation.
+    /// This is a cheaper approximation of [`FloatingVector2::slerp`] for unit vectors.
+    fn nlerp(self, other: Self, t: Self::Scalar) -> Self {
+        self.lerp(other, t).unit()
+    }
 }
 
 impl<T> FloatingVector2 for T
@@ -565,6 +953,59 @@ fn transforms() {
     assert!(v1.dist(v2).is_near_zero(10.0));
 }
 
+#[cfg(test)]
+#[test]
+fn vector_helpers() {
+    let a = [3, -4];
+    let b = [1, 2];
+    assert_eq!(10, a.cross(b));
+    assert_eq!([4, 3], a.perp());
+    assert_eq!([-4, -3], a.perp_cw());
+    assert_eq!([3, 4], a.reflect([0, 1]));
+    assert_eq!([3, 4], a.abs());
+    assert_eq!([1, -1], a.signum());
+    assert_eq!([1, -4], a.min2(b));
+    assert_eq!([3, 2], a.max2(b));
+    assert_eq!([2, 0], a.clamp2([0, 0], [2, 2]));
+    assert_eq!(-1, a.sum());
+    assert_eq!(-12, a.product());
+    let quarter = [1.0, 0.0].angle_between([0.0, 2.0]);
+    assert!((quarter - f64::TAU / 4.0).is_zero());
+    assert!(([3.0, 4.0].clamp_mag(0.0, 1.0).mag() - 1.0).is_zero());
+    assert!([1.0, 0.0]
+        .slerp([0.0, 3.0], 0.5)
+        .dist([2f64.sqrt(), 2f64.sqrt()])
+        .is_near_zero(10.0));
+    let v = [2.0, 5.0];
+    assert_eq!(v, v.project([1.0, 1.0]).add(v.reject([1.0, 1.0])));
+}
+
+#[cfg(test)]
+#[test]
+fn coordinate_conversions() {
+    let v = [-3.0, 4.0];
+    let (radius, angle) = v.to_polar();
+    assert!(v
+        .dist(<[f64; 2]>::from_polar(radius, angle))
+        .is_near_zero(10.0));
+    let (log_radius, angle) = v.to_log_polar();
+    assert!((log_radius - 5f64.ln()).is_zero());
+    assert!(v
+        .dist(<[f64; 2]>::from_log_polar(log_radius, angle))
+        .is_near_zero(10.0));
+    let (a, b, c) = ([0.0, 0.0], [4.0, 0.0], [0.0, 4.0]);
+    let bary = [1.0, 1.0].to_barycentric(a, b, c);
+    assert_eq!([0.5, 0.25, 0.25], bary);
+    assert_eq!([1.0, 1.0], <[f64; 2]>::from_barycentric(bary, a, b, c));
+    let (origin, x_axis, y_axis) = ([1.0, 1.0], [0.0, 2.0], [-1.0, 0.0]);
+    let local = [0.0, 5.0].to_local(origin, x_axis, y_axis);
+    assert_eq!([2.0, 1.0], local);
+    assert_eq!(
+        [0.0, 5.0],
+        <[f64; 2]>::from_local(local, origin, x_axis, y_axis)
+    );
+}
+
 #[cfg(test)]
 #[test]
 fn rect_with_bound() {
@@ -578,3 +1019,16 @@ fn rect_with_bound() {
     assert_eq!(rl1, [1, 0, 4, 5]);
     assert_eq!(rr1, [0, 0, 1, 5]);
 }
+
+#[cfg(test)]
+#[test]
+fn rect_y_up() {
+    let rect = [0, 0, 5, 5].y_up();
+    assert_eq!(5, rect.top());
+    assert_eq!(0, rect.bottom());
+    assert_eq!([[0, 5], [5, 5], [5, 0], [0, 0]], rect.corners());
+    assert_eq!([0, 0, 5, 3], rect.with_top(3).0);
+    assert_eq!([0, 1, 5, 4], rect.with_bottom(1).0);
+    assert_eq!([1, 3, 2, 1], rect.inner_margins([1, 2, 1, 3]).0);
+    assert_eq!([-1, -3, 8, 9], rect.outer_margins([1, 2, 1, 3]).0);
+}
@@ -0,0 +1,178 @@
+//! Module for offsetting polygons and polylines
+
+use crate::{
+    boolean::{boolean, combine, ring_edges},
+    BooleanOp, FillRule, FloatingScalar, FloatingVector2, LineCap, LineJoin, Scalar as _, Stroke,
+};
+
+/**
+Grow or shrink a shape made of polygonal rings by a distance
+
+Positive distances grow the shape and negative distances shrink it.
+The shape's rings are filled with the [`FillRule::EvenOdd`] fill rule,
+as in [`boolean`]. The `join` is used at the corners that the offset moves
+away from, and round joins deviate from the true offset by no more than
+`tolerance`.
+
+Overlaps in the offset rings are resolved, so growing may merge parts of the
+shape and fill in holes, and shrinking may split parts or remove them
+entirely. The result is in the same form as that of [`boolean`].
+```
+use vector2math::*;
+
+let square = [[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]];
+let grown = offset_polygon(&[square], 1.0, LineJoin::Miter(2.0), 0.1);
+assert_eq!(vec![vec![vec![[-1.0, -1.0], [5.0, -1.0], [5.0, 5.0], [-1.0, 5.0]]]], grown);
+
+let shrunk = offset_polygon(&[square], -1.0, LineJoin::Round, 0.1);
+assert_eq!(vec![vec![vec![[1.0, 1.0], [3.0, 1.0], [3.0, 3.0], [1.0, 3.0]]]], shrunk);
+
+assert!(offset_polygon(&[square], -2.5, LineJoin::Round, 0.1).is_empty());
+```
+*/
+pub fn offset_polygon<V, R>(
+    rings: &[R],
+    delta: V::Scalar,
+    join: LineJoin<V::Scalar>,
+    tolerance: V::Scalar,
+) -> Vec<Vec<Vec<V>>>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+    R: AsRef<[V]>,
+{
+    let shape = boolean::<V, R, &[V]>(rings, &[], BooleanOp::Union);
+    if delta.is_zero() {
+        return shape;
+    }
+    let stroke = Stroke::new(delta.abs() * V::Scalar::TWO).with_join(join);
+    let grow = delta > V::Scalar::ZERO;
+    let mut offset = Vec::new();
+    for ring in shape.iter().flatten() {
+        // Outer rings and holes wind so that the side in the
+        // positive angle direction is inside the shape
+        let mut points = ring.clone();
+        if grow {
+            points.reverse();
+        }
+        points.push(points[0]);
+        let mut side = stroke.side(&points, true, tolerance);
+        if grow {
+            side.reverse();
+        }
+        offset.push(side);
+    }
+    // Corners that the offset moves into leave loops that wind backwards,
+    // as do parts of the shape that vanish, so keep what winds forwards
+    combine(&ring_edges(&offset), &[], BooleanOp::Union, |winding| {
+        winding > 0
+    })
+}
+
+/**
+Get the area within a distance of a polyline
+
+If `closed` is true, the last point is connected back to the first. The
+`join` is used at the polyline's corners and the `cap` at its ends, and
+round joins and caps deviate from the true outline by no more than
+`tolerance`. Unlike [`Stroke::outline`], where the polyline crosses itself
+the result does not overlap itself. It is in the same form as that of
+[`boolean`].
+```
+use vector2math::*;
+
+let corner = [[0.0, 0.0], [4.0, 0.0], [4.0, 4.0]];
+let buffer = offset_polyline(&corner, 1.0, LineJoin::Miter(2.0), LineCap::Butt, false, 0.1);
+assert_eq!(
+    vec![vec![vec![[0.0, -1.0], [5.0, -1.0], [5.0, 4.0], [3.0, 4.0], [3.0, 1.0], [0.0, 1.0]]]],
+    buffer
+);
+```
+*/
+pub fn offset_polyline<V>(
+    points: &[V],
+    distance: V::Scalar,
+    join: LineJoin<V::Scalar>,
+    cap: LineCap,
+    closed: bool,
+    tolerance: V::Scalar,
+) -> Vec<Vec<Vec<V>>>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let outline = Stroke::new(distance.abs() * V::Scalar::TWO)
+        .with_join(join)
+        .with_cap(cap)
+        .outline(points, closed, tolerance);
+    combine(&ring_edges(&outline), &[], BooleanOp::Union, |winding| {
+        FillRule::NonZero.is_inside(winding)
+    })
+}
+
+#[cfg(test)]
+#[test]
+fn offset() {
+    use crate::Polygon;
+    let area = |polygons: &[Vec<Vec<[f64; 2]>>]| -> f64 {
+        polygons
+            .iter()
+            .flatten()
+            .map(|ring| ring.signed_area())
+            .sum()
+    };
+    let square = [[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]];
+    let round = square.offset(1.0, LineJoin::Round, 1e-4);
+    assert!((area(&round) - (32.0 + std::f64::consts::PI)).abs() < 1e-2);
+    // Square joins cut the corners of the miter at the offset distance
+    let cut = square.offset(1.0, LineJoin::Square, 0.1);
+    assert_eq!(8, cut[0][0].len());
+    assert!((area(&cut) - (36.0 - 2.0 * (2.0 - 2f64.sqrt()).powi(2))).abs() < 1e-9);
+    // Growing fills in a small hole
+    let framed = [
+        vec![[0.0, 0.0], [6.0, 0.0], [6.0, 6.0], [0.0, 6.0]],
+        vec![[2.0, 2.0], [4.0, 2.0], [4.0, 4.0], [2.0, 4.0]],
+    ];
+    assert_eq!(
+        2,
+        offset_polygon(&framed, 0.5, LineJoin::Bevel, 0.1)[0].len()
+    );
+    assert_eq!(
+        vec![vec![vec![
+            [-1.5, -1.5],
+            [7.5, -1.5],
+            [7.5, 7.5],
+            [-1.5, 7.5]
+        ]]],
+        offset_polygon(&framed, 1.5, LineJoin::Miter(2.0), 0.1)
+    );
+    // Shrinking splits a dumbbell at its narrow bar
+    let dumbbell = [
+        [0.0, 0.0],
+        [3.0, 0.0],
+        [3.0, 1.0],
+        [5.0, 1.0],
+        [5.0, 0.0],
+        [8.0, 0.0],
+        [8.0, 3.0],
+        [5.0, 3.0],
+        [5.0, 2.0],
+        [3.0, 2.0],
+        [3.0, 3.0],
+        [0.0, 3.0],
+    ];
+    // The rounded corners at the ends of the bar bulge out of each half
+    let halves = dumbbell.offset(-0.75, LineJoin::Round, 0.01);
+    assert_eq!(2, halves.len());
+    assert!(area(&halves) > 4.5 && area(&halves) < 5.0);
+    // A polyline that crosses itself
+    let loops = [[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [2.0, 4.0], [2.0, -2.0]];
+    let buffer = offset_polyline(&loops, 0.5, LineJoin::Bevel, LineCap::Butt, false, 0.1);
+    assert_eq!(1, buffer.len());
+    assert_eq!(2, buffer[0].len());
+    for ring in buffer.iter().flatten() {
+        for pair in ring.windows(2) {
+            assert!(pair[0].dist(pair[1]) > 0.0);
+        }
+    }
+}
@@ -0,0 +1,399 @@
+//! Module for the [`OrientedRect`] trait
+
+use std::cmp::Ordering;
+
+use crate::{FloatingScalar, FloatingVector2, Rectangle, Scalar as _, Transform, Vector2};
+
+/// The scalar type of an [`OrientedRect`]
+pub type Scalar<T> = <<T as OrientedRect>::Vector as Vector2>::Scalar;
+
+/**
+Trait for manipulating rotated rectangles
+
+An oriented rectangle is defined by its center, its half extents along its own
+x and y axes, and a rotation in radians of those axes.
+
+It is implemented for any pair of a [`Rectangle`] and a rotation, where the
+rectangle is rotated about its center.
+```
+use vector2math::*;
+
+let orect = ([0.0, 0.0, 4.0, 2.0], f64::TAU / 4.0);
+assert!(orect.contains([2.0, 2.5]));
+assert!(!orect.contains([3.5, 1.0]));
+let bounds: f64::Rect = orect.bounding_rect();
+assert!(bounds.size().dist([2.0, 4.0]) < 1e-9);
+```
+*/
+pub trait OrientedRect: Copy
+where
+    Scalar<Self>: FloatingScalar,
+{
+    /// The vector type
+    type Vector: FloatingVector2;
+    /// Create a new oriented rectangle from a center coordinate,
+    /// half extents, and a rotation in radians
+    fn new(center: Self::Vector, half_extents: Self::Vector, rotation: Scalar<Self>) -> Self;
+    /// Get the center
+    fn center(self) -> Self::Vector;
+    /// Get the half extents along the rectangle's own x and y axes
+    fn half_extents(self) -> Self::Vector;
+    /// Get the rotation in radians
+    fn rotation(self) -> Scalar<Self>;
+    /// Create a new oriented rectangle from an axis-aligned one
+    fn from_rect<R>(rect: R) -> Self
+    where
+        R: Rectangle<Vector = Self::Vector>,
+    {
+        Self::new(
+            rect.center(),
+            rect.abs_size().div(Scalar::<Self>::TWO),
+            Scalar::<Self>::ZERO,
+        )
+    }
+    /// Create a new oriented rectangle by applying a transform to an axis-aligned one
+    ///
+    /// The result is exact for transforms that preserve right angles. If the transform
+    /// skews the rectangle, the result keeps the transformed x axis and the transformed area.
+    fn from_rect_transform<R, T>(rect: R, transform: T) -> Self
+    where
+        R: Rectangle<Vector = Self::Vector>,
+        T: Transform<Scalar = Scalar<Self>> + Copy,
+    {
+        let zero = Scalar::<Self>::ZERO;
+        let half = rect.abs_size().div(Scalar::<Self>::TWO);
+        let center = transform.apply(rect.center());
+        let x_edge = transform
+            .apply(rect.center().add(Self::Vector::new(half.x(), zero)))
+            .sub(center);
+        let y_edge = transform
+            .apply(rect.center().add(Self::Vector::new(zero, half.y())))
+            .sub(center);
+        Self::new(
+            center,
+            Self::Vector::new(x_edge.mag(), x_edge.unit().cross(y_edge).abs()),
+            x_edge.atan(),
+        )
+    }
+    /// Map this oriented rectangle to an oriented rectangle of another type
+    fn map_into<O>(self) -> O
+    where
+        O: OrientedRect,
+        Scalar<O>: FloatingScalar + From<Scalar<Self>>,
+    {
+        O::new(
+            self.center().map_into(),
+            self.half_extents().map_into(),
+            Scalar::<O>::from(self.rotation()),
+        )
+    }
+    /// Map this oriented rectangle to an oriented rectangle of another type using a function
+    fn map_with<O, F>(self, mut f: F) -> O
+    where
+        O: OrientedRect,
+        Scalar<O>: FloatingScalar,
+        F: FnMut(Scalar<Self>) -> <<O as OrientedRect>::Vector as Vector2>::Scalar,
+    {
+        O::new(
+            self.center().map_with(&mut f),
+            self.half_extents().map_with(&mut f),
+            f(self.rotation()),
+        )
+    }
+    /// Transform the oriented rectangle into one with a different center
+    fn with_center(self, center: Self::Vector) -> Self {
+        Self::new(center, self.half_extents(), self.rotation())
+    }
+    /// Transform the oriented rectangle into one with different half extents
+    fn with_half_extents(self, half_extents: Self::Vector) -> Self {
+        Self::new(self.center(), half_extents, self.rotation())
+    }
+    /// Transform the oriented rectangle into one with a different rotation
+    fn with_rotation(self, rotation: Scalar<Self>) -> Self {
+        Self::new(self.center(), self.half_extents(), rotation)
+    }
+    /// Get the size along the rectangle's own x and y axes
+    fn size(self) -> Self::Vector {
+        self.half_extents().mul(Scalar::<Self>::TWO)
+    }
+    /// Get the perimeter
+    fn perimeter(self) -> Scalar<Self> {
+        self.size().abs().sum() * Scalar::<Self>::TWO
+    }
+    /// Get the area
+    fn area(self) -> Scalar<Self> {
+        self.size().product().abs()
+    }
+    /// Get the oriented rectangle that is this one translated by some vector
+    fn translated(self, offset: Self::Vector) -> Self {
+        self.with_center(self.center().add(offset))
+    }
+    /// Get the oriented rectangle that is this one rotated some number of radians about its center
+    fn rotated(self, radians: Scalar<Self>) -> Self {
+        self.with_rotation(self.rotation() + radians)
+    }
+    /// Get the unit vectors of the rectangle's own x and y axes
+    fn axes(self) -> [Self::Vector; 2] {
+        let x_axis = Self::Vector::from_angle(self.rotation());
+        [x_axis, x_axis.perp()]
+    }
+    /// Get an array of the rectangle's four corners
+    ///
+    /// The corners are ordered the same as [`Rectangle::corners`] for
+    /// the rectangle before it is rotated.
+    fn corners(self) -> [Self::Vector; 4] {
+        let [x_axis, y_axis] = self.axes();
+        let x = x_axis.mul(self.half_extents().x());
+        let y = y_axis.mul(self.half_extents().y());
+        let center = self.center();
+        [
+            center.sub(x).sub(y),
+            center.add(x).sub(y),
+            center.add(x).add(y),
+            center.sub(x).add(y),
+        ]
+    }
+    /// Check that the oriented rectangle contains the given point. Includes edges.
+    fn contains(self, point: Self::Vector) -> bool {
+        let [x_axis, y_axis] = self.axes();
+        let offset = point.sub(self.center());
+        let half = self.half_extents().abs();
+        offset.dot(x_axis).abs() <= half.x() && offset.dot(y_axis).abs() <= half.y()
+    }
+    /// Check that the oriented rectangle contains all points
+    fn contains_all<I>(self, points: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        points.into_iter().all(|point| self.contains(point))
+    }
+    /// Check that the oriented rectangle contains any point
+    fn contains_any<I>(self, points: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        points.into_iter().any(|point| self.contains(point))
+    }
+    /// Get the smallest axis-aligned rectangle that contains the oriented rectangle
+    fn bounding_rect<R>(self) -> R
+    where
+        R: Rectangle<Vector = Self::Vector>,
+    {
+        let [x_axis, y_axis] = self.axes();
+        let half = self.half_extents();
+        let half_size = x_axis.mul(half.x()).abs().add(y_axis.mul(half.y()).abs());
+        R::new(
+            self.center().sub(half_size),
+            half_size.mul(Scalar::<Self>::TWO),
+        )
+    }
+    /// Check if this oriented rectangle intersects another. Includes edges.
+    ///
+    /// This uses the separating axis test.
+    fn intersects<O>(self, other: O) -> bool
+    where
+        O: OrientedRect<Vector = Self::Vector>,
+    {
+        let [ax, ay] = self.axes();
+        let [bx, by] = other.axes();
+        let a_half = self.half_extents().abs();
+        let b_half = other.half_extents().abs();
+        let offset = other.center().sub(self.center());
+        [ax, ay, bx, by].iter().all(|&axis| {
+            let a_radius = a_half.x() * ax.dot(axis).abs() + a_half.y() * ay.dot(axis).abs();
+            let b_radius = b_half.x() * bx.dot(axis).abs() + b_half.y() * by.dot(axis).abs();
+            offset.dot(axis).abs() <= a_radius + b_radius
+        })
+    }
+    /// Get the oriented rectangle with the smallest area that contains all the points
+    ///
+    /// One of the edges of the resulting rectangle is always collinear with
+    /// an edge of the points' convex hull. It is found with rotating calipers.
+    ///
+    /// Returns `None` if the iterator is empty
+    fn fit_points<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        fit_hull(points, |width, height| width * height)
+    }
+    /// Get the oriented rectangle with the smallest perimeter that contains all the points
+    ///
+    /// One of the edges of the resulting rectangle is always collinear with
+    /// an edge of the points' convex hull. It is found with rotating calipers.
+    ///
+    /// Returns `None` if the iterator is empty
+    fn fit_points_min_perimeter<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        fit_hull(points, |width, height| width + height)
+    }
+}
+
+impl<R, S> OrientedRect for (R, S)
+where
+    R: Rectangle,
+    R::Vector: FloatingVector2<Scalar = S>,
+    S: FloatingScalar,
+{
+    type Vector = R::Vector;
+    fn new(center: Self::Vector, half_extents: Self::Vector, rotation: Scalar<Self>) -> Self {
+        (R::centered(center, half_extents.mul(S::TWO)), rotation)
+    }
+    fn center(self) -> Self::Vector {
+        self.0.center()
+    }
+    fn half_extents(self) -> Self::Vector {
+        self.0.size().div(S::TWO)
+    }
+    fn rotation(self) -> Scalar<Self> {
+        self.1
+    }
+}
+
+/// Get the convex hull of some points using Andrew's monotone chain algorithm
+///
+/// The hull is counter-clockwise in a Y-up coordinate system, without collinear points.
+fn convex_hull<V>(mut points: Vec<V>) -> Vec<V>
+where
+    V: Vector2,
+{
+    points.sort_by(|a, b| {
+        a.x()
+            .partial_cmp(&b.x())
+            .unwrap_or(Ordering::Equal)
+            .then(a.y().partial_cmp(&b.y()).unwrap_or(Ordering::Equal))
+    });
+    points.dedup_by(|a, b| a.x() == b.x() && a.y() == b.y());
+    if points.len() < 3 {
+        return points;
+    }
+    let mut hull = half_hull(points.iter().copied());
+    hull.extend(half_hull(points.iter().rev().copied()));
+    hull
+}
+
+/// Get one half of a convex hull from points sorted along the x axis, without its last point
+fn half_hull<V, I>(points: I) -> Vec<V>
+where
+    V: Vector2,
+    I: Iterator<Item = V>,
+{
+    let mut hull: Vec<V> = Vec::new();
+    for point in points {
+        while hull.len() >= 2 {
+            let (a, b) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+            if b.sub(a).cross(point.sub(a)) > V::Scalar::ZERO {
+                break;
+            }
+            hull.pop();
+        }
+        hull.push(point);
+    }
+    hull.pop();
+    hull
+}
+
+/// Get the oriented rectangle around some points that minimizes a measure of its width and height
+fn fit_hull<O, I, F>(points: I, measure: F) -> Option<O>
+where
+    O: OrientedRect,
+    Scalar<O>: FloatingScalar,
+    I: IntoIterator<Item = O::Vector>,
+    F: Fn(Scalar<O>, Scalar<O>) -> Scalar<O>,
+{
+    let hull = convex_hull(points.into_iter().collect());
+    let n = hull.len();
+    let zero = Scalar::<O>::ZERO;
+    match n {
+        0 => return None,
+        1 => return Some(O::new(hull[0], O::Vector::new(zero, zero), zero)),
+        _ => {}
+    }
+    let dot = |i: usize, axis: O::Vector| hull[i % n].dot(axis);
+    let x_axis = |i: usize| hull[(i + 1) % n].sub(hull[i]).unit();
+    // The hull is counter-clockwise in Y-up coordinates, so its interior is
+    // on the side of each edge that `perp` points to. The points furthest
+    // along, away from, and back along each edge move around the hull
+    // in the same direction as the edge does.
+    let extreme = |axis: O::Vector, sign: Scalar<O>| {
+        (0..n)
+            .max_by(|&a, &b| {
+                (dot(a, axis) * sign)
+                    .partial_cmp(&(dot(b, axis) * sign))
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap_or(0)
+    };
+    let one = Scalar::<O>::ONE;
+    let mut front = extreme(x_axis(0), one);
+    let mut top = extreme(x_axis(0).perp(), one);
+    let mut back = extreme(x_axis(0), -one);
+    let mut best: Option<(Scalar<O>, O)> = None;
+    for i in 0..n {
+        let x_axis = x_axis(i);
+        let y_axis = x_axis.perp();
+        while dot(front + 1, x_axis) > dot(front, x_axis) {
+            front = (front + 1) % n;
+        }
+        while dot(top + 1, y_axis) > dot(top, y_axis) {
+            top = (top + 1) % n;
+        }
+        while dot(back + 1, x_axis) < dot(back, x_axis) {
+            back = (back + 1) % n;
+        }
+        let (min_x, max_x) = (dot(back, x_axis), dot(front, x_axis));
+        let (min_y, max_y) = (dot(i, y_axis), dot(top, y_axis));
+        let size = measure(max_x - min_x, max_y - min_y);
+        let better = match best {
+            Some((best_size, _)) => size < best_size,
+            None => true,
+        };
+        if better {
+            let two = Scalar::<O>::TWO;
+            let center = x_axis
+                .mul((min_x + max_x) / two)
+                .add(y_axis.mul((min_y + max_y) / two));
+            let half_extents = O::Vector::new(max_x - min_x, max_y - min_y).div(two);
+            best = Some((size, O::new(center, half_extents, x_axis.atan())));
+        }
+    }
+    best.map(|(_, orect)| orect)
+}
+
+#[cfg(test)]
+#[test]
+fn oriented_rect() {
+    let a = crate::f64::ORect::new([0.0, 0.0], [2.0, 1.0], 0.0);
+    let b = crate::f64::ORect::new([3.5, 0.0], [2.0, 0.5], 0.3);
+    let c = crate::f64::ORect::new([3.5, 0.0], [2.0, 0.5], 0.0);
+    assert!(a.intersects(b));
+    assert!(a.intersects(c));
+    assert!(!a.intersects(b.translated([1.0, 1.0])));
+    let transform = crate::f64::Trans::identity()
+        .scale([2.0, 2.0])
+        .rotate(0.5)
+        .translate([1.0, 1.0]);
+    let from_transform = crate::f64::ORect::from_rect_transform([0.0, 0.0, 2.0, 1.0], transform);
+    for (corner, expected) in from_transform
+        .corners()
+        .iter()
+        .zip([0.0, 0.0, 2.0, 1.0].corners().iter())
+    {
+        assert!(corner.dist(expected.transform(transform)) < 1e-9);
+    }
+    let points = [[0.0, 0.0], [2.0, 2.0], [1.0, 3.0], [-1.0, 1.0], [0.5, 1.5]];
+    let fitted = crate::f64::ORect::fit_points(points.iter().copied()).unwrap();
+    assert!((fitted.area() - 4.0).abs() < 1e-9);
+    let padded = fitted.with_half_extents(fitted.half_extents().add([1e-9; 2]));
+    assert!(padded.contains_all(points.iter().copied()));
+    // Each fit is at least as good as the other by its own measure
+    let diamond = [[0.0, 0.0], [4.0, 1.0], [5.0, 5.0], [1.0, 4.0]];
+    let by_area = crate::f64::ORect::fit_points(diamond.iter().copied()).unwrap();
+    let by_perimeter =
+        crate::f64::ORect::fit_points_min_perimeter(diamond.iter().copied()).unwrap();
+    assert!(by_area.area() <= by_perimeter.area() + 1e-9);
+    let perimeter = |orect: crate::f64::ORect| orect.half_extents().sum() * 4.0;
+    assert!(perimeter(by_perimeter) <= perimeter(by_area) + 1e-9);
+}
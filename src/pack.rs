@@ -0,0 +1,504 @@
+//! Module for packing rectangles into a bin
+
+use std::cmp::Ordering;
+
+use crate::{Rectangle, Scalar, Vector2};
+
+/// A heuristic for choosing where to place each rectangle in a [`Packer`]'s bin
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PackAlgorithm {
+    /// Keep track of the top edge of the packed rectangles, and
+    /// place each rectangle as low as possible along it
+    ///
+    /// This is the fastest, but it cannot fill gaps left under the skyline.
+    Skyline,
+    /// Keep track of every maximal free rectangle, and place each
+    /// rectangle in the one that it fits most snugly
+    ///
+    /// This packs the most tightly but is the slowest.
+    #[default]
+    MaxRects,
+    /// Split the free space with cuts across the whole of each free
+    /// rectangle, and place each rectangle in the free rectangle whose
+    /// area it fills most
+    Guillotine,
+}
+
+/// What a [`Packer`] does when the rectangles do not fit in its bin
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BinGrowth<V> {
+    /// Packing fails
+    #[default]
+    Fixed,
+    /// The bin's shorter side is doubled until the rectangles fit,
+    /// but packing fails if the bin would be larger than the given size
+    Double(V),
+}
+
+/// The result of packing rectangles with a [`Packer`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Packing<R> {
+    /// The bin that the rectangles were packed into, which
+    /// may be larger than the requested one if it grew
+    pub bin: R,
+    /// The packed rectangles, in the order of their sizes
+    pub rects: Vec<R>,
+    /// Whether each rectangle was rotated, which swaps its width and height
+    pub rotated: Vec<bool>,
+}
+
+/**
+A builder for packing rectangles into a bin, as for a texture atlas
+
+The bin's top-left corner is at the origin.
+```
+use vector2math::*;
+
+let sizes = [[4, 6], [6, 2], [2, 2], [2, 4]];
+let packer = Packer::new([8, 8]);
+let packing: Packing<[i32; 4]> = packer.pack(&sizes).unwrap();
+for (i, rect) in packing.rects.iter().enumerate() {
+    assert_eq!(sizes[i], rect.size());
+    assert!(packing.bin.contains_all(rect.corners().iter().copied()));
+}
+
+// With padding, the sizes no longer fit, but the bin can grow
+assert!(packer.with_padding(1).pack::<[i32; 4]>(&sizes).is_none());
+let packing: Packing<[i32; 4]> = packer
+    .with_padding(1)
+    .with_growth(BinGrowth::Double([32, 32]))
+    .pack(&sizes)
+    .unwrap();
+assert_eq!([0, 0, 16, 8], packing.bin);
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Packer<V>
+where
+    V: Vector2,
+{
+    /// The size of the bin
+    pub size: V,
+    /// The heuristic used to place rectangles
+    pub algorithm: PackAlgorithm,
+    /// The space left between packed rectangles
+    ///
+    /// No space is left between rectangles and the edges of the bin.
+    pub padding: V::Scalar,
+    /// Whether rectangles may be rotated to pack them better
+    pub rotation: bool,
+    /// What to do when the rectangles do not fit
+    pub growth: BinGrowth<V>,
+}
+
+impl<V> Packer<V>
+where
+    V: Vector2,
+{
+    /// Create a new packer for a bin of the given size with [`PackAlgorithm::MaxRects`],
+    /// no padding or rotation, and a fixed size
+    pub fn new(size: V) -> Self {
+        Packer {
+            size,
+            algorithm: PackAlgorithm::MaxRects,
+            padding: V::Scalar::ZERO,
+            rotation: false,
+            growth: BinGrowth::Fixed,
+        }
+    }
+    /// Transform the packer into one with a different bin size
+    pub fn with_size(self, size: V) -> Self {
+        Packer { size, ..self }
+    }
+    /// Transform the packer into one with a different algorithm
+    pub fn with_algorithm(self, algorithm: PackAlgorithm) -> Self {
+        Packer { algorithm, ..self }
+    }
+    /// Transform the packer into one with different padding
+    pub fn with_padding(self, padding: V::Scalar) -> Self {
+        Packer { padding, ..self }
+    }
+    /// Transform the packer into one that does or does not allow rotation
+    pub fn with_rotation(self, rotation: bool) -> Self {
+        Packer { rotation, ..self }
+    }
+    /// Transform the packer into one with a different growth policy
+    pub fn with_growth(self, growth: BinGrowth<V>) -> Self {
+        Packer { growth, ..self }
+    }
+    /// Pack rectangles of the given sizes into the bin
+    ///
+    /// The rectangles are placed from largest to smallest. Returns `None`
+    /// if they do not fit and the bin cannot grow enough to fit them.
+    pub fn pack<R>(&self, sizes: &[V]) -> Option<Packing<R>>
+    where
+        R: Rectangle<Vector = V>,
+    {
+        let padding = self.padding;
+        let padded: Vec<[V::Scalar; 2]> = sizes
+            .iter()
+            .map(|size| [size.x() + padding, size.y() + padding])
+            .collect();
+        let compare = |a: &V::Scalar, b: &V::Scalar| a.partial_cmp(b).unwrap_or(Ordering::Equal);
+        let mut order: Vec<usize> = (0..sizes.len()).collect();
+        order.sort_by(|&i, &j| {
+            let ([iw, ih], [jw, jh]) = (padded[i], padded[j]);
+            compare(&jw.maxx(jh), &iw.maxx(ih)).then(compare(&jw.minn(jh), &iw.minn(ih)))
+        });
+        let mut bin = [self.size.x(), self.size.y()];
+        loop {
+            // Padding the bin as well lets rectangles reach its edges
+            if let Some(placed) = place_all(
+                self.algorithm,
+                [bin[0] + padding, bin[1] + padding],
+                &padded,
+                &order,
+                self.rotation,
+            ) {
+                let rects = placed
+                    .iter()
+                    .zip(sizes)
+                    .map(|(&(position, rotated), &size)| {
+                        let size = if rotated {
+                            V::new(size.y(), size.x())
+                        } else {
+                            size
+                        };
+                        R::new(position, size)
+                    })
+                    .collect();
+                return Some(Packing {
+                    bin: R::new(V::square(V::Scalar::ZERO), V::new(bin[0], bin[1])),
+                    rects,
+                    rotated: placed.iter().map(|&(_, rotated)| rotated).collect(),
+                });
+            }
+            let max = match self.growth {
+                BinGrowth::Fixed => return None,
+                BinGrowth::Double(max) => [max.x(), max.y()],
+            };
+            let doubled = |side: V::Scalar| (side * V::Scalar::TWO).maxx(V::Scalar::ONE);
+            let shorter = if bin[0] <= bin[1] { 0 } else { 1 };
+            let side = if doubled(bin[shorter]) <= max[shorter] {
+                shorter
+            } else if doubled(bin[1 - shorter]) <= max[1 - shorter] {
+                1 - shorter
+            } else {
+                return None;
+            };
+            bin[side] = doubled(bin[side]);
+        }
+    }
+}
+
+/// Place padded sizes in order, returning the position of each and whether it was rotated
+fn place_all<V>(
+    algorithm: PackAlgorithm,
+    bin: [V::Scalar; 2],
+    sizes: &[[V::Scalar; 2]],
+    order: &[usize],
+    rotation: bool,
+) -> Option<Vec<(V, bool)>>
+where
+    V: Vector2,
+{
+    match algorithm {
+        PackAlgorithm::Skyline => place_in(Skyline::new(bin), sizes, order, rotation),
+        PackAlgorithm::MaxRects => place_in(MaxRects::new(bin), sizes, order, rotation),
+        PackAlgorithm::Guillotine => place_in(Guillotine::new(bin), sizes, order, rotation),
+    }
+}
+
+fn place_in<V, B>(
+    mut bin: B,
+    sizes: &[[V::Scalar; 2]],
+    order: &[usize],
+    rotation: bool,
+) -> Option<Vec<(V, bool)>>
+where
+    V: Vector2,
+    B: Bin<V::Scalar>,
+{
+    let mut placed = vec![(V::square(V::Scalar::ZERO), false); sizes.len()];
+    for &i in order {
+        let [width, height] = sizes[i];
+        let upright = bin
+            .find(width, height)
+            .map(|(position, score)| (position, score, false));
+        let turned = if rotation && width != height {
+            bin.find(height, width)
+                .map(|(position, score)| (position, score, true))
+        } else {
+            None
+        };
+        let ([x, y], _, rotated) = match (upright, turned) {
+            (Some(upright), Some(turned)) if compare_scores(&turned.1, &upright.1).is_lt() => {
+                turned
+            }
+            (Some(upright), _) => upright,
+            (None, turned) => turned?,
+        };
+        if rotated {
+            bin.insert([x, y], height, width);
+        } else {
+            bin.insert([x, y], width, height);
+        }
+        placed[i] = (V::new(x, y), rotated);
+    }
+    Some(placed)
+}
+
+/// A score for a placement, where lower is better
+type Score<S> = (S, S);
+
+/// The free space of a bin during packing
+trait Bin<S> {
+    /// Find the best position for a rectangle of a size, and the placement's score
+    fn find(&self, width: S, height: S) -> Option<([S; 2], Score<S>)>;
+    /// Place a rectangle at a position returned by [`Bin::find`]
+    fn insert(&mut self, position: [S; 2], width: S, height: S);
+}
+
+/// Segments of the skyline, as `[x, y, width]`
+struct Skyline<S> {
+    size: [S; 2],
+    segments: Vec<[S; 3]>,
+}
+
+impl<S: Scalar> Skyline<S> {
+    fn new(size: [S; 2]) -> Self {
+        Skyline {
+            size,
+            segments: vec![[S::ZERO, S::ZERO, size[0]]],
+        }
+    }
+    /// Get the height at which a rectangle of a width would rest if its left were at a segment
+    fn rest(&self, start: usize, width: S) -> Option<S> {
+        let left = self.segments[start][0];
+        if left + width > self.size[0] {
+            return None;
+        }
+        let mut top = S::ZERO;
+        for &[x, y, _] in &self.segments[start..] {
+            if x >= left + width {
+                break;
+            }
+            top = top.maxx(y);
+        }
+        Some(top)
+    }
+}
+
+impl<S: Scalar> Bin<S> for Skyline<S> {
+    fn find(&self, width: S, height: S) -> Option<([S; 2], Score<S>)> {
+        // Prefer the position where the rectangle's bottom is highest
+        (0..self.segments.len())
+            .filter_map(|i| {
+                let top = self.rest(i, width)?;
+                if top + height > self.size[1] {
+                    return None;
+                }
+                let left = self.segments[i][0];
+                Some(([left, top], (top + height, left)))
+            })
+            .min_by(|(_, a), (_, b)| compare_scores(a, b))
+    }
+    fn insert(&mut self, [left, top]: [S; 2], width: S, height: S) {
+        let spot = self
+            .segments
+            .iter()
+            .position(|segment| segment[0] == left)
+            .unwrap_or(0);
+        let right = left + width;
+        // Trim the segments that are now under the rectangle
+        let mut i = spot;
+        while i < self.segments.len() && self.segments[i][0] < right {
+            let [x, y, w] = self.segments[i];
+            if x + w <= right {
+                self.segments.remove(i);
+            } else {
+                self.segments[i] = [right, y, x + w - right];
+                i += 1;
+            }
+        }
+        self.segments.insert(spot, [left, top + height, width]);
+        // Merge segments at the same height
+        let mut i = 0;
+        while i + 1 < self.segments.len() {
+            if self.segments[i][1] == self.segments[i + 1][1] {
+                self.segments[i][2] = self.segments[i][2] + self.segments[i + 1][2];
+                self.segments.remove(i + 1);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+/// Maximal free rectangles, as `[x, y, width, height]`
+struct MaxRects<S> {
+    free: Vec<[S; 4]>,
+}
+
+impl<S: Scalar> MaxRects<S> {
+    fn new(size: [S; 2]) -> Self {
+        MaxRects {
+            free: vec![[S::ZERO, S::ZERO, size[0], size[1]]],
+        }
+    }
+}
+
+impl<S: Scalar> Bin<S> for MaxRects<S> {
+    fn find(&self, width: S, height: S) -> Option<([S; 2], Score<S>)> {
+        // Prefer the free rectangle with the least space left along its shorter side
+        self.free
+            .iter()
+            .filter(|free| free[2] >= width && free[3] >= height)
+            .map(|free| {
+                let (dw, dh) = (free[2] - width, free[3] - height);
+                ([free[0], free[1]], (dw.minn(dh), dw.maxx(dh)))
+            })
+            .min_by(|(_, a), (_, b)| compare_scores(a, b))
+    }
+    fn insert(&mut self, [x, y]: [S; 2], width: S, height: S) {
+        let placed = [x, y, x + width, y + height];
+        let mut split = Vec::new();
+        self.free.retain(|&[fx, fy, fw, fh]| {
+            let (fr, fb) = (fx + fw, fy + fh);
+            if placed[0] >= fr || placed[2] <= fx || placed[1] >= fb || placed[3] <= fy {
+                return true;
+            }
+            // Keep the parts of the free rectangle on each side of the placed one
+            if placed[0] > fx {
+                split.push([fx, fy, placed[0] - fx, fh]);
+            }
+            if placed[2] < fr {
+                split.push([placed[2], fy, fr - placed[2], fh]);
+            }
+            if placed[1] > fy {
+                split.push([fx, fy, fw, placed[1] - fy]);
+            }
+            if placed[3] < fb {
+                split.push([fx, placed[3], fw, fb - placed[3]]);
+            }
+            false
+        });
+        // Only the new rectangles can be inside others, since the rest were already maximal
+        let contains = |a: &[S; 4], b: &[S; 4]| {
+            a[0] <= b[0] && a[1] <= b[1] && b[0] + b[2] <= a[0] + a[2] && b[1] + b[3] <= a[1] + a[3]
+        };
+        let kept = self.free.len();
+        for (i, rect) in split.iter().enumerate() {
+            let inside = self.free[..kept].iter().any(|free| contains(free, rect))
+                || split
+                    .iter()
+                    .enumerate()
+                    .any(|(j, other)| j != i && contains(other, rect) && (other != rect || j < i));
+            if !inside {
+                self.free.push(*rect);
+            }
+        }
+    }
+}
+
+/// Free rectangles left by guillotine cuts, as `[x, y, width, height]`
+struct Guillotine<S> {
+    free: Vec<[S; 4]>,
+}
+
+impl<S: Scalar> Guillotine<S> {
+    fn new(size: [S; 2]) -> Self {
+        Guillotine {
+            free: vec![[S::ZERO, S::ZERO, size[0], size[1]]],
+        }
+    }
+}
+
+impl<S: Scalar> Bin<S> for Guillotine<S> {
+    fn find(&self, width: S, height: S) -> Option<([S; 2], Score<S>)> {
+        // Prefer the smallest free rectangle
+        self.free
+            .iter()
+            .filter(|free| free[2] >= width && free[3] >= height)
+            .map(|free| {
+                let (dw, dh) = (free[2] - width, free[3] - height);
+                (
+                    [free[0], free[1]],
+                    (free[2] * free[3] - width * height, dw.minn(dh)),
+                )
+            })
+            .min_by(|(_, a), (_, b)| compare_scores(a, b))
+    }
+    fn insert(&mut self, position: [S; 2], width: S, height: S) {
+        // Free rectangles do not overlap, so each has a different top-left corner
+        let spot = self
+            .free
+            .iter()
+            .position(|free| [free[0], free[1]] == position)
+            .unwrap_or(0);
+        let [x, y, w, h] = self.free.swap_remove(spot);
+        let (dw, dh) = (w - width, h - height);
+        // Cut along the shorter leftover axis, so the larger leftover is as big as possible
+        let (right, below) = if dw < dh {
+            ([x + width, y, dw, height], [x, y + height, w, dh])
+        } else {
+            ([x + width, y, dw, h], [x, y + height, width, dh])
+        };
+        for free in [right, below].iter() {
+            if free[2] > S::ZERO && free[3] > S::ZERO {
+                self.free.push(*free);
+            }
+        }
+    }
+}
+
+fn compare_scores<S: Scalar>(a: &Score<S>, b: &Score<S>) -> Ordering {
+    a.0.partial_cmp(&b.0)
+        .unwrap_or(Ordering::Equal)
+        .then(a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+}
+
+#[cfg(test)]
+#[test]
+fn pack() {
+    let overlaps = |a: [i32; 4], b: [i32; 4]| {
+        a.left() < b.right() && b.left() < a.right() && a.top() < b.bottom() && b.top() < a.bottom()
+    };
+    let sizes: Vec<[i32; 2]> = (1..=12).map(|i| [i % 5 + 1, (i * 7) % 6 + 1]).collect();
+    for &algorithm in &[
+        PackAlgorithm::Skyline,
+        PackAlgorithm::MaxRects,
+        PackAlgorithm::Guillotine,
+    ] {
+        for &rotation in &[false, true] {
+            let packing: Packing<[i32; 4]> = Packer::new([4, 4])
+                .with_algorithm(algorithm)
+                .with_rotation(rotation)
+                .with_padding(1)
+                .with_growth(BinGrowth::Double([64, 64]))
+                .pack(&sizes)
+                .unwrap();
+            let rects = &packing.rects;
+            for (i, rect) in rects.iter().enumerate() {
+                let size = if packing.rotated[i] {
+                    [sizes[i][1], sizes[i][0]]
+                } else {
+                    sizes[i]
+                };
+                assert_eq!(size, rect.size());
+                assert!(rotation || !packing.rotated[i]);
+                assert!(packing.bin.contains_all(rect.corners().iter().copied()));
+                // Padding keeps rectangles apart
+                for other in &rects[i + 1..] {
+                    assert!(!overlaps(rect.outer_margin(1), *other));
+                }
+            }
+        }
+    }
+    // Rotation lets a tall rectangle fit in a wide bin
+    let packer = Packer::new([4, 2]);
+    assert!(packer.pack::<[i32; 4]>(&[[2, 4]]).is_none());
+    let packing: Packing<[i32; 4]> = packer.with_rotation(true).pack(&[[2, 4]]).unwrap();
+    assert_eq!(vec![[0, 0, 4, 2]], packing.rects);
+    assert_eq!(vec![true], packing.rotated);
+}
@@ -0,0 +1,1014 @@
+//! Module for the [`Path`] type
+
+use std::{cmp::Ordering, error::Error, fmt, str::FromStr};
+
+use crate::{
+    capsule::closest_on_segment, polygon::edge_winding, CubicBezier, Ellipse, FillRule,
+    FloatingScalar, FloatingVector2, QuadBezier, Rectangle, Scalar as _, Transform, Vector2,
+};
+
+/// A segment of a [`Path`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathSegment<V>
+where
+    V: Vector2,
+{
+    /// Start a new subpath at a point
+    MoveTo(V),
+    /// Draw a line to a point
+    LineTo(V),
+    /// Draw a quadratic Bézier curve with a control point to a point
+    QuadTo(V, V),
+    /// Draw a cubic Bézier curve with two control points to a point
+    CubicTo(V, V, V),
+    /// Draw an elliptical arc to a point
+    ///
+    /// This uses the same parameters as the SVG arc command, except that
+    /// the rotation is in radians. If `sweep` is true, the arc goes in
+    /// the positive angle direction.
+    ArcTo {
+        /// The radii of the arc's ellipse
+        radii: V,
+        /// The rotation of the arc's ellipse in radians
+        rotation: V::Scalar,
+        /// Whether to take the longer of the possible arcs
+        large_arc: bool,
+        /// Whether the arc goes in the positive angle direction
+        sweep: bool,
+        /// The end point
+        to: V,
+    },
+    /// Draw a line back to the start of the current subpath and close it
+    Close,
+}
+
+/**
+A sequence of lines and curves
+
+Paths are made up of subpaths, each of which starts with a [`PathSegment::MoveTo`]
+and may be closed with a [`PathSegment::Close`].
+
+Paths can be built with builder methods, or parsed from and serialized to
+[SVG path data](https://www.w3.org/TR/SVG/paths.html#PathData).
+```
+use vector2math::*;
+
+let path = Path::new()
+    .move_to([0.0, 0.0])
+    .line_to([10.0, 0.0])
+    .quad_to([10.0, 10.0], [0.0, 10.0])
+    .close();
+assert_eq!("M0 0H10Q10 10 0 10Z", path.to_string());
+assert_eq!(Ok(path.clone()), "m0 0 10 0q0 10-10 10z".parse());
+
+let bounds: f64::Rect = path.bounding_rect().unwrap();
+assert_eq!([0.0, 0.0, 10.0, 10.0], bounds);
+```
+*/
+#[derive(Clone)]
+pub struct Path<V>
+where
+    V: Vector2,
+{
+    segments: Vec<PathSegment<V>>,
+}
+
+impl<V> fmt::Debug for Path<V>
+where
+    V: Vector2,
+    PathSegment<V>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Path")
+            .field("segments", &self.segments)
+            .finish()
+    }
+}
+
+impl<V> PartialEq for Path<V>
+where
+    V: Vector2,
+    PathSegment<V>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.segments == other.segments
+    }
+}
+
+impl<V> Default for Path<V>
+where
+    V: Vector2,
+{
+    fn default() -> Self {
+        Path {
+            segments: Vec::new(),
+        }
+    }
+}
+
+/// A path segment with its start point resolved and arcs converted to cubic curves
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Piece<V> {
+    Move(V),
+    Line(V, V),
+    Quad([V; 3]),
+    Cubic([V; 4]),
+    /// The closing line of a subpath
+    Close(V, V),
+}
+
+impl<V> Path<V>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    /// Create a new empty path
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Get the path's segments
+    pub fn segments(&self) -> &[PathSegment<V>] {
+        &self.segments
+    }
+    /// Check if the path has no segments
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+    /// Add a segment to the path
+    pub fn push(&mut self, segment: PathSegment<V>) {
+        self.segments.push(segment);
+    }
+    /// Start a new subpath at a point
+    pub fn move_to(mut self, point: V) -> Self {
+        self.push(PathSegment::MoveTo(point));
+        self
+    }
+    /// Draw a line to a point
+    pub fn line_to(mut self, point: V) -> Self {
+        self.push(PathSegment::LineTo(point));
+        self
+    }
+    /// Draw a quadratic Bézier curve with a control point to a point
+    pub fn quad_to(mut self, control: V, point: V) -> Self {
+        self.push(PathSegment::QuadTo(control, point));
+        self
+    }
+    /// Draw a cubic Bézier curve with two control points to a point
+    pub fn cubic_to(mut self, control1: V, control2: V, point: V) -> Self {
+        self.push(PathSegment::CubicTo(control1, control2, point));
+        self
+    }
+    /// Draw an elliptical arc to a point
+    ///
+    /// See [`PathSegment::ArcTo`] for the meaning of the parameters.
+    pub fn arc_to(
+        mut self,
+        radii: V,
+        rotation: V::Scalar,
+        large_arc: bool,
+        sweep: bool,
+        point: V,
+    ) -> Self {
+        self.push(PathSegment::ArcTo {
+            radii,
+            rotation,
+            large_arc,
+            sweep,
+            to: point,
+        });
+        self
+    }
+    /// Close the current subpath
+    pub fn close(mut self) -> Self {
+        self.push(PathSegment::Close);
+        self
+    }
+    /// Get the path with all of its arcs converted to cubic Bézier curves
+    pub fn without_arcs(&self) -> Self {
+        let mut path = Path::new();
+        for piece in self.pieces() {
+            path.push(match piece {
+                Piece::Move(to) => PathSegment::MoveTo(to),
+                Piece::Line(_, to) => PathSegment::LineTo(to),
+                Piece::Quad([_, c, to]) => PathSegment::QuadTo(c, to),
+                Piece::Cubic([_, c1, c2, to]) => PathSegment::CubicTo(c1, c2, to),
+                Piece::Close(..) => PathSegment::Close,
+            });
+        }
+        path
+    }
+    /// Get the path that is the result of applying a transform to this one
+    ///
+    /// Because affine transforms map ellipses to ellipses, arcs are transformed exactly.
+    pub fn transformed<T>(&self, transform: T) -> Self
+    where
+        T: Transform<Scalar = V::Scalar> + Copy,
+    {
+        let zero = V::Scalar::ZERO;
+        let one = V::Scalar::ONE;
+        let origin = transform.apply(V::square(zero));
+        let x_axis = transform.apply(V::new(one, zero)).sub(origin);
+        let y_axis = transform.apply(V::new(zero, one)).sub(origin);
+        let flips = x_axis.cross(y_axis) < zero;
+        let segments = self
+            .segments
+            .iter()
+            .map(|&segment| match segment {
+                PathSegment::MoveTo(to) => PathSegment::MoveTo(transform.apply(to)),
+                PathSegment::LineTo(to) => PathSegment::LineTo(transform.apply(to)),
+                PathSegment::QuadTo(c, to) => {
+                    PathSegment::QuadTo(transform.apply(c), transform.apply(to))
+                }
+                PathSegment::CubicTo(c1, c2, to) => PathSegment::CubicTo(
+                    transform.apply(c1),
+                    transform.apply(c2),
+                    transform.apply(to),
+                ),
+                PathSegment::ArcTo {
+                    radii,
+                    rotation,
+                    large_arc,
+                    sweep,
+                    to,
+                } => {
+                    let ellipse = <(V, V, V::Scalar)>::new(origin, radii.abs(), rotation)
+                        .transformed(transform);
+                    PathSegment::ArcTo {
+                        radii: ellipse.radii(),
+                        rotation: ellipse.rotation(),
+                        large_arc,
+                        sweep: sweep != flips,
+                        to: transform.apply(to),
+                    }
+                }
+                PathSegment::Close => PathSegment::Close,
+            })
+            .collect();
+        Path { segments }
+    }
+    /// Get the smallest axis-aligned rectangle that contains the path
+    ///
+    /// Returns `None` if the path is empty.
+    pub fn bounding_rect<R>(&self) -> Option<R>
+    where
+        R: Rectangle<Vector = V>,
+    {
+        let mut bounds: Option<(V, V)> = None;
+        let mut include = |min: V, max: V| {
+            bounds = Some(match bounds {
+                Some((a, b)) => (a.min2(min), b.max2(max)),
+                None => (min, max),
+            });
+        };
+        for piece in self.pieces() {
+            match piece {
+                Piece::Move(p) => include(p, p),
+                Piece::Line(a, b) | Piece::Close(a, b) => include(a.min2(b), a.max2(b)),
+                Piece::Quad(curve) => {
+                    let rect: R = curve.bounding_rect();
+                    include(rect.top_left(), rect.bottom_right());
+                }
+                Piece::Cubic(curve) => {
+                    let rect: R = curve.bounding_rect();
+                    include(rect.top_left(), rect.bottom_right());
+                }
+            }
+        }
+        bounds.map(|(min, max)| R::new(min, max.sub(min)))
+    }
+    /// Get the number of times the path winds around a point
+    ///
+    /// Every subpath is treated as closed. Windings that are counter-clockwise
+    /// in a Y-up coordinate system are positive. Curves are tested exactly
+    /// rather than being flattened.
+    pub fn winding_number(&self, point: V) -> i32 {
+        let mut winding = 0;
+        // The start and current point of the current subpath
+        let mut subpath: Option<(V, V)> = None;
+        for piece in self.pieces() {
+            let (from, to) = match piece {
+                Piece::Move(to) => {
+                    if let Some((start, current)) = subpath {
+                        winding += edge_winding(current, start, point);
+                    }
+                    subpath = Some((to, to));
+                    continue;
+                }
+                Piece::Line(a, b) | Piece::Close(a, b) => {
+                    winding += edge_winding(a, b, point);
+                    (a, b)
+                }
+                Piece::Quad(curve) => {
+                    winding += curve_winding(curve.to_cubic(), point);
+                    (curve.start(), curve.end())
+                }
+                Piece::Cubic(curve) => {
+                    winding += curve_winding(curve, point);
+                    (curve.start(), curve.end())
+                }
+            };
+            subpath = Some((subpath.map_or(from, |(start, _)| start), to));
+        }
+        if let Some((start, current)) = subpath {
+            winding += edge_winding(current, start, point);
+        }
+        winding
+    }
+    /// Check that the path contains a point using the given fill rule
+    ///
+    /// Every subpath is treated as closed. Points exactly on
+    /// the boundary may be considered either inside or outside.
+    pub fn contains(&self, point: V, rule: FillRule) -> bool {
+        rule.is_inside(self.winding_number(point))
+    }
+    /// Get the distance from a point to the nearest point on the path
+    ///
+    /// Only closed subpaths include their closing lines.
+    /// Returns infinity if the path has no lines or curves.
+    pub fn boundary_distance(&self, point: V) -> V::Scalar {
+        self.pieces()
+            .into_iter()
+            .map(|piece| match piece {
+                Piece::Move(_) => V::Scalar::infinity(),
+                Piece::Line(a, b) | Piece::Close(a, b) => {
+                    point.dist(closest_on_segment(point, a, b))
+                }
+                Piece::Quad(curve) => point.dist(curve.nearest_point(point)),
+                Piece::Cubic(curve) => point.dist(curve.nearest_point(point)),
+            })
+            .fold(V::Scalar::infinity(), |min, dist| min.minn(dist))
+    }
+    /// Check if a point is on the path when it is stroked with the given width
+    pub fn stroke_contains(&self, point: V, width: V::Scalar) -> bool {
+        self.boundary_distance(point) <= width.abs() / V::Scalar::TWO
+    }
+    /// Get points along each subpath
+    ///
+    /// Each subpath is returned along with whether it is closed. The closing
+    /// line of a closed subpath is implied and its start point is not repeated.
+    /// Curves are subdivided so that the polylines deviate from them by
+    /// no more than `tolerance`.
+    pub fn flatten(&self, tolerance: V::Scalar) -> Vec<(Vec<V>, bool)> {
+        let mut subpaths = Vec::new();
+        let mut points: Vec<V> = Vec::new();
+        for piece in self.pieces() {
+            match piece {
+                Piece::Move(p) => {
+                    if !points.is_empty() {
+                        subpaths.push((points, false));
+                    }
+                    points = vec![p];
+                }
+                Piece::Line(a, b) => {
+                    if points.is_empty() {
+                        points.push(a);
+                    }
+                    points.push(b);
+                }
+                Piece::Quad(curve) => {
+                    if points.is_empty() {
+                        points.push(curve.start());
+                    }
+                    points.extend(curve.flatten(tolerance).into_iter().skip(1));
+                }
+                Piece::Cubic(curve) => {
+                    if points.is_empty() {
+                        points.push(curve.start());
+                    }
+                    points.extend(curve.flatten(tolerance).into_iter().skip(1));
+                }
+                Piece::Close(a, _) => {
+                    if points.is_empty() {
+                        points.push(a);
+                    }
+                    subpaths.push((points, true));
+                    points = Vec::new();
+                }
+            }
+        }
+        if !points.is_empty() {
+            subpaths.push((points, false));
+        }
+        subpaths
+    }
+    /// Get the path's segments with their start points resolved and arcs converted to cubics
+    pub(crate) fn pieces(&self) -> Vec<Piece<V>> {
+        let mut pieces = Vec::with_capacity(self.segments.len());
+        let mut current = V::square(V::Scalar::ZERO);
+        let mut start = current;
+        for &segment in &self.segments {
+            match segment {
+                PathSegment::MoveTo(to) => {
+                    pieces.push(Piece::Move(to));
+                    start = to;
+                    current = to;
+                }
+                PathSegment::LineTo(to) => {
+                    pieces.push(Piece::Line(current, to));
+                    current = to;
+                }
+                PathSegment::QuadTo(c, to) => {
+                    pieces.push(Piece::Quad([current, c, to]));
+                    current = to;
+                }
+                PathSegment::CubicTo(c1, c2, to) => {
+                    pieces.push(Piece::Cubic([current, c1, c2, to]));
+                    current = to;
+                }
+                PathSegment::ArcTo {
+                    radii,
+                    rotation,
+                    large_arc,
+                    sweep,
+                    to,
+                } => {
+                    if radii.x().is_zero() || radii.y().is_zero() {
+                        pieces.push(Piece::Line(current, to));
+                    } else {
+                        pieces.extend(
+                            arc_to_cubics(current, radii, rotation, large_arc, sweep, to)
+                                .into_iter()
+                                .map(Piece::Cubic),
+                        );
+                    }
+                    current = to;
+                }
+                PathSegment::Close => {
+                    pieces.push(Piece::Close(current, start));
+                    current = start;
+                }
+            }
+        }
+        pieces
+    }
+}
+
+impl<V> Path<V>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar + FromStr,
+{
+    /// Parse SVG path data
+    ///
+    /// This is the same as using [`str::parse`].
+    pub fn parse(data: &str) -> Result<Self, ParsePathError> {
+        Parser {
+            data,
+            pos: 0,
+            path: Path::new(),
+        }
+        .parse()
+    }
+}
+
+impl<V> FromStr for Path<V>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar + FromStr,
+{
+    type Err = ParsePathError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Path::parse(s)
+    }
+}
+
+/// An error that can occur when parsing SVG path data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParsePathError {
+    /// The path data did not start with a move command
+    MissingMoveTo,
+    /// An unexpected character was found at the given byte index
+    UnexpectedChar(char, usize),
+    /// A number was expected at the given byte index
+    ExpectedNumber(usize),
+    /// An arc flag of `0` or `1` was expected at the given byte index
+    ExpectedFlag(usize),
+}
+
+impl fmt::Display for ParsePathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParsePathError::MissingMoveTo => write!(f, "path data must start with a move command"),
+            ParsePathError::UnexpectedChar(c, i) => {
+                write!(f, "unexpected character {:?} at index {}", c, i)
+            }
+            ParsePathError::ExpectedNumber(i) => write!(f, "expected a number at index {}", i),
+            ParsePathError::ExpectedFlag(i) => write!(f, "expected an arc flag at index {}", i),
+        }
+    }
+}
+
+impl Error for ParsePathError {}
+
+struct Parser<'a, V>
+where
+    V: Vector2,
+{
+    data: &'a str,
+    pos: usize,
+    path: Path<V>,
+}
+
+impl<'a, V> Parser<'a, V>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar + FromStr,
+{
+    fn peek(&self) -> Option<u8> {
+        self.data.as_bytes().get(self.pos).copied()
+    }
+    fn skip_separators(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0C' | b',') = self.peek() {
+            self.pos += 1;
+        }
+    }
+    fn skip_digits(&mut self) -> usize {
+        let start = self.pos;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+    fn has_number(&mut self) -> bool {
+        self.skip_separators();
+        matches!(self.peek(), Some(b'0'..=b'9' | b'.' | b'-' | b'+'))
+    }
+    fn number(&mut self) -> Result<V::Scalar, ParsePathError> {
+        self.skip_separators();
+        let start = self.pos;
+        if let Some(b'-' | b'+') = self.peek() {
+            self.pos += 1;
+        }
+        let mut digits = self.skip_digits();
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            digits += self.skip_digits();
+        }
+        if digits == 0 {
+            return Err(ParsePathError::ExpectedNumber(start));
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            let before_exponent = self.pos;
+            self.pos += 1;
+            if let Some(b'-' | b'+') = self.peek() {
+                self.pos += 1;
+            }
+            if self.skip_digits() == 0 {
+                self.pos = before_exponent;
+            }
+        }
+        self.data[start..self.pos]
+            .parse()
+            .map_err(|_| ParsePathError::ExpectedNumber(start))
+    }
+    fn point(&mut self, relative_to: Option<V>) -> Result<V, ParsePathError> {
+        let point = V::new(self.number()?, self.number()?);
+        Ok(match relative_to {
+            Some(origin) => origin.add(point),
+            None => point,
+        })
+    }
+    fn flag(&mut self) -> Result<bool, ParsePathError> {
+        self.skip_separators();
+        let flag = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(ParsePathError::ExpectedFlag(self.pos)),
+        };
+        self.pos += 1;
+        Ok(flag)
+    }
+    fn parse(mut self) -> Result<Path<V>, ParsePathError> {
+        let zero = V::square(V::Scalar::ZERO);
+        let (mut current, mut start) = (zero, zero);
+        // The second control point of the previous segment if it was a curve
+        // of the same order as the current command, for smooth curves
+        let mut prev_cubic_control: Option<V> = None;
+        let mut prev_quad_control: Option<V> = None;
+        loop {
+            self.skip_separators();
+            let command = match self.peek() {
+                Some(b) => b,
+                None => break,
+            };
+            if !command.is_ascii_alphabetic()
+                || b"MmZzLlHhVvCcSsQqTtAa".iter().all(|&c| c != command)
+            {
+                let c = self.data[self.pos..].chars().next().unwrap_or('\0');
+                return Err(ParsePathError::UnexpectedChar(c, self.pos));
+            }
+            if self.path.is_empty() && !matches!(command, b'M' | b'm') {
+                return Err(ParsePathError::MissingMoveTo);
+            }
+            self.pos += 1;
+            let relative = command.is_ascii_lowercase();
+            let mut first = true;
+            loop {
+                let origin = if relative { Some(current) } else { None };
+                let segment = match command.to_ascii_uppercase() {
+                    b'M' if first => {
+                        let to = self.point(origin)?;
+                        start = to;
+                        PathSegment::MoveTo(to)
+                    }
+                    b'M' | b'L' => PathSegment::LineTo(self.point(origin)?),
+                    b'H' => {
+                        let x = self.number()?;
+                        let x = if relative { current.x() + x } else { x };
+                        PathSegment::LineTo(V::new(x, current.y()))
+                    }
+                    b'V' => {
+                        let y = self.number()?;
+                        let y = if relative { current.y() + y } else { y };
+                        PathSegment::LineTo(V::new(current.x(), y))
+                    }
+                    b'C' => PathSegment::CubicTo(
+                        self.point(origin)?,
+                        self.point(origin)?,
+                        self.point(origin)?,
+                    ),
+                    b'S' => {
+                        let c1 = prev_cubic_control
+                            .map(|c| current.mul(V::Scalar::TWO).sub(c))
+                            .unwrap_or(current);
+                        PathSegment::CubicTo(c1, self.point(origin)?, self.point(origin)?)
+                    }
+                    b'Q' => PathSegment::QuadTo(self.point(origin)?, self.point(origin)?),
+                    b'T' => {
+                        let c = prev_quad_control
+                            .map(|c| current.mul(V::Scalar::TWO).sub(c))
+                            .unwrap_or(current);
+                        PathSegment::QuadTo(c, self.point(origin)?)
+                    }
+                    b'A' => {
+                        let radii = V::new(self.number()?, self.number()?);
+                        let degrees = self.number()?;
+                        let large_arc = self.flag()?;
+                        let sweep = self.flag()?;
+                        PathSegment::ArcTo {
+                            radii,
+                            rotation: degrees * V::Scalar::PI / V::Scalar::from_usize(180),
+                            large_arc,
+                            sweep,
+                            to: self.point(origin)?,
+                        }
+                    }
+                    _ => PathSegment::Close,
+                };
+                prev_cubic_control = None;
+                prev_quad_control = None;
+                match segment {
+                    PathSegment::MoveTo(to) | PathSegment::LineTo(to) => current = to,
+                    PathSegment::QuadTo(c, to) => {
+                        prev_quad_control = Some(c);
+                        current = to;
+                    }
+                    PathSegment::CubicTo(_, c2, to) => {
+                        prev_cubic_control = Some(c2);
+                        current = to;
+                    }
+                    PathSegment::ArcTo { to, .. } => current = to,
+                    PathSegment::Close => current = start,
+                }
+                self.path.push(segment);
+                first = false;
+                if matches!(segment, PathSegment::Close) || !self.has_number() {
+                    break;
+                }
+            }
+        }
+        Ok(self.path)
+    }
+}
+
+impl<V> fmt::Display for Path<V>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut writer = PathWriter {
+            f,
+            prev_command: None,
+            prev_number: None,
+        };
+        let zero = V::square(V::Scalar::ZERO);
+        let (mut current, mut start) = (zero, zero);
+        for &segment in &self.segments {
+            match segment {
+                PathSegment::MoveTo(to) => {
+                    writer.command('M', &[to.x(), to.y()])?;
+                    start = to;
+                    current = to;
+                }
+                PathSegment::LineTo(to) => {
+                    if to.y() == current.y() && to.x() != current.x() {
+                        writer.command('H', &[to.x()])?;
+                    } else if to.x() == current.x() && to.y() != current.y() {
+                        writer.command('V', &[to.y()])?;
+                    } else {
+                        writer.command('L', &[to.x(), to.y()])?;
+                    }
+                    current = to;
+                }
+                PathSegment::QuadTo(c, to) => {
+                    writer.command('Q', &[c.x(), c.y(), to.x(), to.y()])?;
+                    current = to;
+                }
+                PathSegment::CubicTo(c1, c2, to) => {
+                    writer.command('C', &[c1.x(), c1.y(), c2.x(), c2.y(), to.x(), to.y()])?;
+                    current = to;
+                }
+                PathSegment::ArcTo {
+                    radii,
+                    rotation,
+                    large_arc,
+                    sweep,
+                    to,
+                } => {
+                    let degrees = rotation * V::Scalar::from_usize(180) / V::Scalar::PI;
+                    writer.command('A', &[radii.x(), radii.y(), degrees])?;
+                    writer.flag(large_arc)?;
+                    writer.flag(sweep)?;
+                    writer.number(to.x())?;
+                    writer.number(to.y())?;
+                    current = to;
+                }
+                PathSegment::Close => {
+                    writer.command::<V::Scalar>('Z', &[])?;
+                    current = start;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes compact path data
+struct PathWriter<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    prev_command: Option<char>,
+    prev_number: Option<String>,
+}
+
+impl<'a, 'b> PathWriter<'a, 'b> {
+    fn command<S>(&mut self, command: char, numbers: &[S]) -> fmt::Result
+    where
+        S: FloatingScalar + fmt::Display,
+    {
+        // Repeated commands can be left out, and lines implicitly follow moves
+        let implicit = match self.prev_command {
+            Some('M') => command == 'L',
+            Some(prev) => prev == command && command != 'Z',
+            None => false,
+        };
+        if !implicit {
+            write!(self.f, "{}", command)?;
+            self.prev_number = None;
+        }
+        self.prev_command = Some(command);
+        for &n in numbers {
+            self.number(n)?;
+        }
+        Ok(())
+    }
+    fn number<S>(&mut self, n: S) -> fmt::Result
+    where
+        S: FloatingScalar + fmt::Display,
+    {
+        let mut s = if n.is_zero() {
+            String::from("0")
+        } else {
+            n.to_string()
+        };
+        if s.starts_with("0.") {
+            s.remove(0);
+        } else if s.starts_with("-0.") {
+            s.remove(1);
+        }
+        if let Some(prev) = &self.prev_number {
+            let needs_separator = !(s.starts_with('-')
+                || s.starts_with('.') && prev.contains('.') && !prev.contains(['e', 'E'].as_ref()));
+            if needs_separator {
+                write!(self.f, " ")?;
+            }
+        }
+        write!(self.f, "{}", s)?;
+        self.prev_number = Some(s);
+        Ok(())
+    }
+    fn flag(&mut self, flag: bool) -> fmt::Result {
+        if self.prev_number.is_some() {
+            write!(self.f, " ")?;
+        }
+        write!(self.f, "{}", flag as u8)?;
+        self.prev_number = Some(String::from("0"));
+        Ok(())
+    }
+}
+
+/// Get the winding contribution of a cubic curve for a ray from a point toward +x
+fn curve_winding<V>(curve: [V; 4], point: V) -> i32
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    if curve.iter().all(|p| p.x() <= point.x())
+        || curve.iter().all(|p| p.y() > point.y())
+        || curve.iter().all(|p| p.y() <= point.y())
+    {
+        return 0;
+    }
+    // Between its extrema, the curve is monotonic in y, so
+    // it crosses the ray at most once
+    let mut splits = curve.extrema();
+    splits.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    splits.push(V::Scalar::ONE);
+    let mut winding = 0;
+    let (mut t0, mut a) = (V::Scalar::ZERO, curve.start());
+    for t1 in splits {
+        let b = if t1 == V::Scalar::ONE {
+            curve.end()
+        } else {
+            curve.eval(t1)
+        };
+        let upward = a.y() <= point.y() && point.y() < b.y();
+        let downward = b.y() <= point.y() && point.y() < a.y();
+        if upward || downward {
+            // Find the crossing by bisection
+            let (mut low, mut high) = (t0, t1);
+            for _ in 0..64 {
+                let mid = (low + high) / V::Scalar::TWO;
+                if (curve.eval(mid).y() <= point.y()) == upward {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            let crossing = curve.eval((low + high) / V::Scalar::TWO);
+            if crossing.x() > point.x() {
+                winding += if upward { 1 } else { -1 };
+            }
+        }
+        t0 = t1;
+        a = b;
+    }
+    winding
+}
+
+/// Convert an SVG-style elliptical arc to cubic Bézier curves
+///
+/// Each curve covers at most a quarter turn of the ellipse.
+fn arc_to_cubics<V>(
+    from: V,
+    radii: V,
+    rotation: V::Scalar,
+    large_arc: bool,
+    sweep: bool,
+    to: V,
+) -> Vec<[V; 4]>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let zero = V::Scalar::ZERO;
+    let one = V::Scalar::ONE;
+    let two = V::Scalar::TWO;
+    if from.squared_dist(to).is_zero() {
+        return Vec::new();
+    }
+    // Find the center parameterization as described in the SVG specification
+    let (sin, cos) = (rotation.sin(), rotation.cos());
+    let rotate = |v: V| V::new(cos * v.x() - sin * v.y(), sin * v.x() + cos * v.y());
+    let unrotate = |v: V| V::new(cos * v.x() + sin * v.y(), cos * v.y() - sin * v.x());
+    let p = unrotate(from.sub(to).div(two));
+    let mut radii = radii.abs();
+    let lambda = (p.x() / radii.x()).square() + (p.y() / radii.y()).square();
+    if lambda > one {
+        radii = radii.mul(lambda.sqrt());
+    }
+    let (rx2, ry2) = (radii.x().square(), radii.y().square());
+    let (px2, py2) = (p.x().square(), p.y().square());
+    let denominator = rx2 * py2 + ry2 * px2;
+    let mut coef = ((rx2 * ry2 - denominator) / denominator).maxx(zero).sqrt();
+    if large_arc == sweep {
+        coef = -coef;
+    }
+    let center_local = V::new(
+        coef * radii.x() * p.y() / radii.y(),
+        -coef * radii.y() * p.x() / radii.x(),
+    );
+    let center = rotate(center_local).add(from.lerp(to, one / two));
+    let u = p.sub(center_local).div2(radii);
+    let v = p.neg().sub(center_local).div2(radii);
+    let start_angle = u.y().atan2(u.x());
+    let mut sweep_angle = u.angle_between(v);
+    if sweep && sweep_angle < zero {
+        sweep_angle = sweep_angle + V::Scalar::TAU;
+    } else if !sweep && sweep_angle > zero {
+        sweep_angle = sweep_angle - V::Scalar::TAU;
+    }
+    // Approximate each quarter turn or less with a cubic curve
+    let quarter = V::Scalar::PI / two;
+    let count = (sweep_angle.abs() / quarter).ceil().to_usize().max(1);
+    let step = sweep_angle / V::Scalar::from_usize(count);
+    let k = (two + two) / (two + one) * (step / (two + two)).sin() / (step / (two + two)).cos();
+    let map = |unit: V| center.add(rotate(unit.mul2(radii)));
+    let mut curves = Vec::with_capacity(count);
+    let mut prev = from;
+    for i in 0..count {
+        let a0 = start_angle + step * V::Scalar::from_usize(i);
+        let a1 = a0 + step;
+        let (e0, e1) = (V::from_angle(a0), V::from_angle(a1));
+        let (d0, d1) = (e0.perp(), e1.perp());
+        let end = if i + 1 == count { to } else { map(e1) };
+        curves.push([prev, map(e0.add(d0.mul(k))), map(e1.sub(d1.mul(k))), end]);
+        prev = end;
+    }
+    curves
+}
+
+#[cfg(test)]
+#[test]
+fn path() {
+    use crate::f64;
+    let data =
+        "M10-20l5 5h10v-5.5e1 H0V0.5 c1,1 2 2 3 3s4 4 5 5 Q1 1 2 2t3 3zm1 1A5 5 30 1 0 20 20z";
+    let path: Path<f64::Vec2> = data.parse().unwrap();
+    assert_eq!(14, path.segments().len());
+    assert_eq!(PathSegment::LineTo([15.0, -15.0]), path.segments()[1]);
+    assert_eq!(PathSegment::LineTo([25.0, -70.0]), path.segments()[3]);
+    assert_eq!(
+        PathSegment::CubicTo([4.0, 4.5], [7.0, 7.5], [8.0, 8.5]),
+        path.segments()[7]
+    );
+    assert_eq!(
+        PathSegment::QuadTo([3.0, 3.0], [5.0, 5.0]),
+        path.segments()[9]
+    );
+    assert_eq!(PathSegment::MoveTo([11.0, -19.0]), path.segments()[11]);
+    let round_trip: Path<f64::Vec2> = path.to_string().parse().unwrap();
+    assert_eq!(path.segments().len(), round_trip.segments().len());
+    for (a, b) in path.segments().iter().zip(round_trip.segments()) {
+        if let (PathSegment::ArcTo { rotation: r1, .. }, PathSegment::ArcTo { rotation: r2, .. }) =
+            (a, b)
+        {
+            assert!((r1 - r2).abs() < 1e-12);
+        } else {
+            assert_eq!(a, b);
+        }
+    }
+    assert_eq!(
+        Err(ParsePathError::MissingMoveTo),
+        Path::<f64::Vec2>::parse("L1 1")
+    );
+    assert_eq!(
+        Err(ParsePathError::ExpectedNumber(5)),
+        Path::<f64::Vec2>::parse("M1 1L")
+    );
+    assert_eq!(
+        Err(ParsePathError::ExpectedFlag(11)),
+        Path::<f64::Vec2>::parse("M0 0A1 1 0 2 0 1 1")
+    );
+    assert_eq!(
+        Err(ParsePathError::UnexpectedChar('x', 4)),
+        Path::<f64::Vec2>::parse("M0 0x")
+    );
+    // A half circle arc has its points on the circle
+    let arc = Path::new()
+        .move_to([0.0, 0.0])
+        .arc_to([1.0, 1.0], 0.0, false, true, [2.0, 0.0]);
+    let bounds: f64::Rect = arc.bounding_rect().unwrap();
+    assert!(bounds.top_left().dist([0.0, -1.0]) < 1e-3);
+    assert!(bounds.bottom_right().dist([2.0, 0.0]) < 1e-3);
+    for &point in &arc.flatten(1e-3)[0].0 {
+        assert!((point.dist([1.0, 0.0]) - 1.0).abs() < 1e-3);
+    }
+    // Hit-testing uses the curves directly
+    let circle: Path<f64::Vec2> =
+        "M0 0A1 1 0 0 0 2 0A1 1 0 0 0 0 0ZM.5 0A.5 .5 0 0 0 1.5 0A.5 .5 0 0 0 .5 0Z"
+            .parse()
+            .unwrap();
+    assert_eq!(2, circle.winding_number([1.0, 0.25]).abs());
+    assert_eq!(1, circle.winding_number([1.0, 0.75]).abs());
+    assert_eq!(0, circle.winding_number([1.0, 1.25]));
+    assert!(circle.contains([1.0, 0.75], FillRule::EvenOdd));
+    assert!(!circle.contains([1.0, 0.25], FillRule::EvenOdd));
+    assert!(circle.stroke_contains([1.0, 1.05], 0.11));
+    assert!(!circle.stroke_contains([1.0, 1.05], 0.09));
+    let open = Path::new()
+        .move_to([0.0, 0.0])
+        .quad_to([1.0, 2.0], [2.0, 0.0]);
+    assert_eq!(1, open.winding_number([1.0, 0.5]).abs());
+    assert!(!open.stroke_contains([1.0, 0.0], 0.5));
+    // Transformed arcs trace the transformed flattened path
+    let transform = f64::Trans::identity().scale([2.0, -1.0]).rotate(0.5);
+    let transformed = arc.transformed(transform).flatten(1e-4);
+    let expected: f64::Vec2 = transform.apply(arc.flatten(1e-4)[0].0[10]);
+    let nearest = transformed[0]
+        .0
+        .iter()
+        .map(|p| p.dist(expected))
+        .fold(1.0, |a: f64, b| a.min(b));
+    assert!(nearest < 1e-3);
+}
@@ -0,0 +1,464 @@
+//! Module for the [`Polygon`] trait
+
+use std::cmp::Ordering;
+
+use crate::{
+    capsule::closest_on_segment,
+    hull::orientation,
+    offset_polygon,
+    triangulate::{ear_clip, monotone},
+    Circle, FloatingScalar, FloatingVector2, LineJoin, Scalar as _, Vector2,
+};
+
+/// The scalar type of a [`Polygon`]
+pub type Scalar<T> = <<T as Polygon>::Vector as Vector2>::Scalar;
+
+/// A rule for deciding which points are inside a shape whose boundary may overlap itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FillRule {
+    /// A point is inside if a ray from it crosses the boundary an odd number of times
+    EvenOdd,
+    /// A point is inside if the boundary winds around it a nonzero number of times
+    #[default]
+    NonZero,
+}
+
+impl FillRule {
+    /// Check if a point with the given winding number is inside
+    pub fn is_inside(self, winding_number: i32) -> bool {
+        match self {
+            FillRule::EvenOdd => winding_number % 2 != 0,
+            FillRule::NonZero => winding_number != 0,
+        }
+    }
+}
+
+/// The direction in which a polygon's vertices go around it
+///
+/// Directions are as they appear in the default Y-down coordinate system,
+/// where the corners of a [`Rectangle`](crate::Rectangle) are clockwise.
+/// They appear reversed in a Y-up coordinate system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Winding {
+    /// Clockwise when the y axis points down
+    #[default]
+    Clockwise,
+    /// Counter-clockwise when the y axis points down
+    CounterClockwise,
+}
+
+/**
+Trait for manipulating polygons
+
+A polygon is a closed sequence of vertices. The last vertex is implicitly
+connected back to the first.
+
+It is implemented for slices of any [`Vector2`] type. Most methods work
+exactly for integer vectors, including unsigned ones. Because slices already
+have a `contains` method, the method for checking if a polygon contains
+a point is called [`Polygon::contains_point`].
+```
+use vector2math::*;
+
+// A square with a square hole, both wound the same way
+let shape = [[0, 0], [4, 0], [4, 4], [0, 4], [0, 0], [1, 1], [3, 1], [3, 3], [1, 3], [1, 1]];
+assert_eq!(2, shape.winding_number([2, 2]));
+assert!(shape.contains_point([2, 2], FillRule::NonZero));
+assert!(!shape.contains_point([2, 2], FillRule::EvenOdd));
+assert!(shape.contains_point([4, 2], FillRule::EvenOdd));
+
+// Triangles are given as indices into the vertices
+let square = [[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0], [0.5, 0.5], [1.5, 0.5], [1.5, 1.5], [0.5, 1.5]];
+assert_eq!(2, square[..4].triangulate().len());
+assert_eq!(8, square.triangulate_with_holes(&[4]).len());
+```
+*/
+pub trait Polygon {
+    /// The vector type
+    type Vector: Vector2;
+    /// Get the polygon's vertices
+    fn vertices(&self) -> &[Self::Vector];
+    /// Get the number of times the polygon winds around a point
+    ///
+    /// Windings that are counter-clockwise in a Y-up coordinate system are positive.
+    fn winding_number(&self, point: Self::Vector) -> i32 {
+        let vertices = self.vertices();
+        vertices
+            .iter()
+            .enumerate()
+            .map(|(i, &a)| edge_winding(a, vertices[(i + 1) % vertices.len()], point))
+            .sum()
+    }
+    /// Check if a point lies exactly on one of the polygon's edges
+    fn on_boundary(&self, point: Self::Vector) -> bool {
+        let vertices = self.vertices();
+        vertices.iter().enumerate().any(|(i, &a)| {
+            let b = vertices[(i + 1) % vertices.len()];
+            let (min, max) = (a.min2(b), a.max2(b));
+            min.x() <= point.x()
+                && point.x() <= max.x()
+                && min.y() <= point.y()
+                && point.y() <= max.y()
+                && orientation(a, b, point) == Ordering::Equal
+        })
+    }
+    /// Check that the polygon contains a point using the given fill rule. Includes edges.
+    fn contains_point(&self, point: Self::Vector, rule: FillRule) -> bool {
+        rule.is_inside(self.winding_number(point)) || self.on_boundary(point)
+    }
+    /// Check that the polygon contains all points using the given fill rule
+    fn contains_all<I>(&self, points: I, rule: FillRule) -> bool
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        points
+            .into_iter()
+            .all(|point| self.contains_point(point, rule))
+    }
+    /// Check that the polygon contains any point using the given fill rule
+    fn contains_any<I>(&self, points: I, rule: FillRule) -> bool
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        points
+            .into_iter()
+            .any(|point| self.contains_point(point, rule))
+    }
+    /// Get the signed area of the polygon
+    ///
+    /// The area is positive if the polygon is counter-clockwise in a Y-up coordinate system.
+    /// For integer vectors, the area is exact except that halves are rounded toward zero,
+    /// and for unsigned ones, only counter-clockwise polygons do not underflow.
+    fn signed_area(&self) -> Scalar<Self> {
+        let vertices = self.vertices();
+        // Sum the two halves of the cross products separately so that
+        // unsigned scalars are only subtracted once
+        let (positive, negative) = vertices.iter().enumerate().fold(
+            (Scalar::<Self>::ZERO, Scalar::<Self>::ZERO),
+            |(positive, negative), (i, &a)| {
+                let b = vertices[(i + 1) % vertices.len()];
+                (positive + a.x() * b.y(), negative + a.y() * b.x())
+            },
+        );
+        (positive - negative) / Scalar::<Self>::TWO
+    }
+    /// Get the distance from a point to the nearest of the polygon's edges
+    fn boundary_distance(&self, point: Self::Vector) -> Scalar<Self>
+    where
+        Self::Vector: FloatingVector2,
+        Scalar<Self>: FloatingScalar,
+    {
+        let vertices = self.vertices();
+        vertices
+            .iter()
+            .enumerate()
+            .map(|(i, &a)| {
+                let b = vertices[(i + 1) % vertices.len()];
+                point.dist(closest_on_segment(point, a, b))
+            })
+            .fold(Scalar::<Self>::infinity(), |min, dist| min.minn(dist))
+    }
+    /// Check if a point is on the polygon's outline when it is stroked with the given width
+    fn stroke_contains(&self, point: Self::Vector, width: Scalar<Self>) -> bool
+    where
+        Self::Vector: FloatingVector2,
+        Scalar<Self>: FloatingScalar,
+    {
+        self.boundary_distance(point) <= width.abs() / Scalar::<Self>::TWO
+    }
+    /// Split the polygon into triangles by ear clipping
+    ///
+    /// Each triangle is given as indices into the polygon's vertices and
+    /// winds the same way as the polygon. The polygon should be simple.
+    fn triangulate(&self) -> Vec<[usize; 3]>
+    where
+        Self::Vector: FloatingVector2,
+        Scalar<Self>: FloatingScalar,
+    {
+        ear_clip(self.vertices(), &[])
+    }
+    /// Split a polygon with holes into triangles by ear clipping
+    ///
+    /// The vertices are the outer boundary followed by each hole, and
+    /// `hole_starts` holds the index at which each hole begins.
+    /// Holes may wind either way.
+    fn triangulate_with_holes(&self, hole_starts: &[usize]) -> Vec<[usize; 3]>
+    where
+        Self::Vector: FloatingVector2,
+        Scalar<Self>: FloatingScalar,
+    {
+        ear_clip(self.vertices(), hole_starts)
+    }
+    /// Split the polygon into triangles by first partitioning it into monotone pieces
+    ///
+    /// This produces the same kind of output as [`Polygon::triangulate`],
+    /// but it scales better to polygons with many vertices.
+    fn triangulate_monotone(&self) -> Vec<[usize; 3]>
+    where
+        Self::Vector: FloatingVector2,
+        Scalar<Self>: FloatingScalar,
+    {
+        monotone(self.vertices())
+    }
+    /// Grow or shrink the polygon by a distance
+    ///
+    /// Positive distances grow the polygon and negative distances shrink it.
+    /// See [`offset_polygon`](crate::offset_polygon) for details.
+    fn offset(
+        &self,
+        delta: Scalar<Self>,
+        join: LineJoin<Scalar<Self>>,
+        tolerance: Scalar<Self>,
+    ) -> Vec<Vec<Vec<Self::Vector>>>
+    where
+        Self::Vector: FloatingVector2,
+        Scalar<Self>: FloatingScalar,
+    {
+        offset_polygon(&[self.vertices()], delta, join, tolerance)
+    }
+    /// Get the largest circle that fits inside the polygon, which should be convex
+    ///
+    /// Returns `None` if the polygon has fewer than three vertices
+    fn largest_inscribed_circle<C>(&self) -> Option<C>
+    where
+        C: Circle<Vector = Self::Vector>,
+        Self::Vector: FloatingVector2,
+        Scalar<Self>: FloatingScalar,
+    {
+        let vertices = self.vertices();
+        if vertices.len() < 3 {
+            return None;
+        }
+        let counter_clockwise = self.signed_area() >= Scalar::<Self>::ZERO;
+        // Repeated vertices make zero-length edges, which have no direction
+        let edges: Vec<(Self::Vector, Self::Vector)> = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, &a)| (a, vertices[(i + 1) % vertices.len()]))
+            .filter(|(a, b)| a.x() != b.x() || a.y() != b.y())
+            .map(|(a, b)| {
+                let direction = b.sub(a).unit();
+                let inward = if counter_clockwise {
+                    direction.perp()
+                } else {
+                    direction.perp_cw()
+                };
+                (a, inward)
+            })
+            .collect();
+        // Binary search for the largest distance that the edges can be moved
+        // inward while still leaving some of the polygon
+        let shrink = |distance: Scalar<Self>| {
+            edges
+                .iter()
+                .fold(vertices.to_vec(), |region, &(a, inward)| {
+                    clip_half_plane(&region, |p| distance - p.sub(a).dot(inward))
+                })
+        };
+        let (min, max) = vertices
+            .iter()
+            .fold((vertices[0], vertices[0]), |(min, max), &v| {
+                (min.min2(v), max.max2(v))
+            });
+        let two = Scalar::<Self>::TWO;
+        let size = max.sub(min);
+        let (mut low, mut high) = (Scalar::<Self>::ZERO, size.x().minn(size.y()) / two);
+        let mut region = shrink(low);
+        loop {
+            let mid = (low + high) / two;
+            if mid <= low || mid >= high {
+                break;
+            }
+            let shrunk = shrink(mid);
+            if shrunk.is_empty() {
+                high = mid;
+            } else {
+                low = mid;
+                region = shrunk;
+            }
+        }
+        let center = region
+            .iter()
+            .fold(Self::Vector::square(Scalar::<Self>::ZERO), |sum, &p| {
+                sum.add(p)
+            })
+            .div(Scalar::<Self>::from_usize(region.len().max(1)));
+        Some(C::new(center, low))
+    }
+    /// Clip a line segment to the part inside the polygon, which must be convex,
+    /// using the Cyrus–Beck algorithm
+    ///
+    /// Returns `None` if the segment is entirely outside
+    fn convex_clip_segment(&self, a: Self::Vector, b: Self::Vector) -> Option<[Self::Vector; 2]>
+    where
+        Self::Vector: FloatingVector2,
+        Scalar<Self>: FloatingScalar,
+    {
+        let zero = Scalar::<Self>::ZERO;
+        let delta = b.sub(a);
+        let (mut enter, mut exit) = (zero, Scalar::<Self>::ONE);
+        // Each edge is a constraint `start + t * rate >= 0` on the position `t` along the segment
+        for (p, edge) in convex_edges(self.vertices())? {
+            let (start, rate) = (edge.cross(a.sub(p)), edge.cross(delta));
+            if rate.is_zero() {
+                if start < zero {
+                    return None;
+                }
+            } else if rate > zero {
+                enter = enter.maxx(-start / rate);
+            } else {
+                exit = exit.minn(-start / rate);
+            }
+        }
+        if enter > exit {
+            return None;
+        }
+        Some([a.lerp(b, enter), a.lerp(b, exit)])
+    }
+    /// Clip a polygon to the part inside this one, which must be convex,
+    /// using the Sutherland–Hodgman algorithm
+    ///
+    /// If the clipped polygon is concave, the result may have edges along
+    /// this polygon's boundary that connect its separate parts.
+    /// Returns an empty polygon if this one has no area.
+    fn convex_clip_polygon(&self, polygon: &[Self::Vector]) -> Vec<Self::Vector>
+    where
+        Self::Vector: FloatingVector2,
+        Scalar<Self>: FloatingScalar,
+    {
+        match convex_edges(self.vertices()) {
+            Some(edges) => edges
+                .into_iter()
+                .fold(polygon.to_vec(), |clipped, (p, edge)| {
+                    clip_half_plane(&clipped, |v| -edge.cross(v.sub(p)))
+                }),
+            None => Vec::new(),
+        }
+    }
+}
+
+impl<V> Polygon for [V]
+where
+    V: Vector2,
+{
+    type Vector = V;
+    fn vertices(&self) -> &[Self::Vector] {
+        self
+    }
+}
+
+/// Get the start and direction of each edge of a convex polygon,
+/// reversed if necessary so that the inside is on their left
+///
+/// Returns `None` if the polygon has no area
+fn convex_edges<V>(vertices: &[V]) -> Option<Vec<(V, V)>>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let area = vertices.signed_area();
+    if area.is_zero() {
+        return None;
+    }
+    let sign = if area > V::Scalar::ZERO {
+        V::Scalar::ONE
+    } else {
+        -V::Scalar::ONE
+    };
+    Some(
+        vertices
+            .iter()
+            .enumerate()
+            .map(|(i, &a)| (a, vertices[(i + 1) % vertices.len()].sub(a).mul(sign)))
+            .collect(),
+    )
+}
+
+/// Clip a polygon to the part where `side` is not positive
+pub(crate) fn clip_half_plane<V, F>(polygon: &[V], side: F) -> Vec<V>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+    F: Fn(V) -> V::Scalar,
+{
+    let zero = V::Scalar::ZERO;
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        let (sa, sb) = (side(a), side(b));
+        if sa <= zero {
+            clipped.push(a);
+        }
+        if (sa < zero && sb > zero) || (sa > zero && sb < zero) {
+            clipped.push(a.lerp(b, sa / (sa - sb)));
+        }
+    }
+    clipped
+}
+
+/// Get the winding contribution of the edge `a -> b` for a ray from a point toward +x
+pub(crate) fn edge_winding<V>(a: V, b: V, point: V) -> i32
+where
+    V: Vector2,
+{
+    if a.y() <= point.y() {
+        if b.y() > point.y() && orientation(a, b, point) == Ordering::Greater {
+            return 1;
+        }
+    } else if b.y() <= point.y() && orientation(a, b, point) == Ordering::Less {
+        return -1;
+    }
+    0
+}
+
+#[cfg(test)]
+#[test]
+fn polygon() {
+    // A bowtie whose halves wind in opposite directions
+    let bowtie = [[0.0, 0.0], [2.0, 2.0], [2.0, 0.0], [0.0, 2.0]];
+    assert_eq!(-1, bowtie.winding_number([1.5, 1.0]));
+    assert_eq!(1, bowtie.winding_number([0.5, 1.0]));
+    assert_eq!(0, bowtie.winding_number([1.0, 1.5]));
+    assert!(bowtie.contains_point([1.0, 1.0], FillRule::EvenOdd));
+    assert!(bowtie.stroke_contains([2.05, 1.0], 0.2));
+    assert!(!bowtie.stroke_contains([2.05, 1.0], 0.05));
+    // The halves of the bowtie cancel out
+    assert_eq!(0.0, bowtie.signed_area());
+    assert_eq!(-6.0, [[0.0, 0.0], [0.0, 3.0], [4.0, 0.0]].signed_area());
+    let unsigned: Vec<[u32; 2]> = vec![[1, 1], [5, 1], [5, 5], [1, 5]];
+    assert!(unsigned.contains_point([5, 3], FillRule::NonZero));
+    assert_eq!(16, unsigned.signed_area());
+    assert_eq!(-6, [[0, 0], [0, 3], [4, 0]].signed_area());
+    assert!(!unsigned.contains_point([0, 3], FillRule::NonZero));
+    assert_eq!(0, unsigned.winding_number([6, 3]));
+    assert!(unsigned.on_boundary([3, 5]));
+    // The largest circle in a triangle is its incircle, regardless of winding
+    let triangle = [[0.0, 0.0], [0.0, 3.0], [4.0, 0.0]];
+    let incircle: crate::f64::Circ = crate::Triangle::incircle(triangle);
+    for vertices in [triangle, [triangle[0], triangle[2], triangle[1]]].iter() {
+        let circle: crate::f64::Circ = vertices.largest_inscribed_circle().unwrap();
+        assert!(circle.center().dist(incircle.center()) < 1e-6);
+        assert!((circle.radius() - incircle.radius()).abs() < 1e-9);
+    }
+    // Repeated vertices do not shrink the circle
+    let closed = [[0.0, 0.0], [0.0, 3.0], [4.0, 0.0], [0.0, 0.0]];
+    let repeated = [[0.0, 0.0], [0.0, 3.0], [0.0, 3.0], [4.0, 0.0]];
+    for vertices in [closed, repeated].iter() {
+        let circle: crate::f64::Circ = vertices.largest_inscribed_circle().unwrap();
+        assert!((circle.radius() - 1.0).abs() < 1e-9);
+    }
+    // Clipping to a convex polygon, which may wind either way
+    let diamond = [[2.0, 0.0], [0.0, 2.0], [-2.0, 0.0], [0.0, -2.0]];
+    assert_eq!(
+        Some([[-2.0, 0.0], [2.0, 0.0]]),
+        diamond.convex_clip_segment([-3.0, 0.0], [3.0, 0.0])
+    );
+    assert_eq!(None, diamond.convex_clip_segment([2.0, 2.0], [3.0, 0.0]));
+    let square = [[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]];
+    assert_eq!(
+        vec![[0.0, 0.0], [2.0, 0.0], [0.0, 2.0]],
+        diamond.convex_clip_polygon(&square)
+    );
+    let flat = [[0.0, 0.0], [4.0, 0.0], [2.0, 0.0]];
+    assert!(flat.convex_clip_polygon(&square).is_empty());
+}
@@ -0,0 +1,180 @@
+//! Module for the [`QuadBezier`] trait
+
+use crate::{CubicBezier, FloatingScalar, FloatingVector2, Rectangle, Scalar as _, Trio, Vector2};
+
+/// The scalar type of a [`QuadBezier`]
+pub type Scalar<T> = <<T as QuadBezier>::Vector as Vector2>::Scalar;
+
+/**
+Trait for manipulating quadratic Bézier curves
+
+It is implemented for any [`Trio`] of [`FloatingVector2`]s, like `[V; 3]` and `(V, V, V)`.
+The first and last control points are the curve's endpoints.
+
+Because those types are also [`Triangle`](crate::Triangle)s, the methods of this trait
+are named so as not to clash with those of [`Triangle`](crate::Triangle).
+```
+use vector2math::*;
+
+let curve = [[0.0, 0.0], [1.0, 2.0], [2.0, 0.0]];
+assert_eq!([1.0, 1.0], curve.eval(0.5));
+assert_eq!([2.0, 0.0], curve.derivative(0.5));
+let bounds: f64::Rect = curve.bounding_rect();
+assert_eq!([0.0, 0.0, 2.0, 1.0], bounds);
+let cubic: f64::CubicBez = curve.to_cubic();
+assert_eq!(curve.eval(0.25), cubic.eval(0.25));
+```
+*/
+pub trait QuadBezier: Copy
+where
+    Scalar<Self>: FloatingScalar,
+{
+    /// The vector type
+    type Vector: FloatingVector2;
+    /// Create a new curve from its control points
+    fn from_control_points(points: [Self::Vector; 3]) -> Self;
+    /// Get the curve's control points
+    fn control_points(self) -> [Self::Vector; 3];
+    /// Get the start point
+    fn start(self) -> Self::Vector {
+        self.control_points()[0]
+    }
+    /// Get the end point
+    fn end(self) -> Self::Vector {
+        self.control_points()[2]
+    }
+    /// Get the curve that goes the opposite direction
+    fn reversed(self) -> Self {
+        let [a, b, c] = self.control_points();
+        Self::from_control_points([c, b, a])
+    }
+    /// Get the point on the curve at the parameter `t`, which goes from 0 to 1
+    fn eval(self, t: Scalar<Self>) -> Self::Vector {
+        let [a, b, c] = self.control_points();
+        a.lerp(b, t).lerp(b.lerp(c, t), t)
+    }
+    /// Get the derivative of the curve with respect to its parameter at `t`
+    fn derivative(self, t: Scalar<Self>) -> Self::Vector {
+        let [a, b, c] = self.control_points();
+        b.sub(a).lerp(c.sub(b), t).mul(Scalar::<Self>::TWO)
+    }
+    /// Get the second derivative of the curve with respect to its parameter
+    ///
+    /// It is the same everywhere along the curve.
+    fn second_derivative(self) -> Self::Vector {
+        let [a, b, c] = self.control_points();
+        c.sub(b.mul(Scalar::<Self>::TWO))
+            .add(a)
+            .mul(Scalar::<Self>::TWO)
+    }
+    /// Split the curve into two at the parameter `t`
+    fn split(self, t: Scalar<Self>) -> (Self, Self) {
+        let [a, b, c] = self.control_points();
+        let ab = a.lerp(b, t);
+        let bc = b.lerp(c, t);
+        let mid = ab.lerp(bc, t);
+        (
+            Self::from_control_points([a, ab, mid]),
+            Self::from_control_points([mid, bc, c]),
+        )
+    }
+    /// Get the parameters in `(0, 1)` where the curve's tangent is
+    /// horizontal or vertical
+    fn extrema(self) -> Vec<Scalar<Self>> {
+        let [a, b, c] = self.control_points();
+        let numerator = a.sub(b);
+        let denominator = a.sub(b.mul(Scalar::<Self>::TWO)).add(c);
+        [
+            (numerator.x(), denominator.x()),
+            (numerator.y(), denominator.y()),
+        ]
+        .iter()
+        .filter(|(_, d)| !d.is_zero())
+        .map(|&(n, d)| n / d)
+        .filter(|&t| Scalar::<Self>::ZERO < t && t < Scalar::<Self>::ONE)
+        .collect()
+    }
+    /// Get the smallest axis-aligned rectangle that contains the curve
+    fn bounding_rect<R>(self) -> R
+    where
+        R: Rectangle<Vector = Self::Vector>,
+    {
+        let mut min = self.start().min2(self.end());
+        let mut max = self.start().max2(self.end());
+        for t in self.extrema() {
+            let point = self.eval(t);
+            min = min.min2(point);
+            max = max.max2(point);
+        }
+        R::new(min, max.sub(min))
+    }
+    /// Get the cubic Bézier curve that traces the same path
+    fn to_cubic<C>(self) -> C
+    where
+        C: CubicBezier<Vector = Self::Vector>,
+    {
+        let [a, b, c] = self.control_points();
+        let two_thirds = Scalar::<Self>::TWO / (Scalar::<Self>::TWO + Scalar::<Self>::ONE);
+        C::from_control_points([a, a.lerp(b, two_thirds), c.lerp(b, two_thirds), c])
+    }
+    /// Check if the curve is within `tolerance` of the line segment between its endpoints
+    fn is_flat(self, tolerance: Scalar<Self>) -> bool {
+        self.to_cubic::<[Self::Vector; 4]>().is_flat(tolerance)
+    }
+    /// Get an estimate of the curve's length
+    ///
+    /// The curve is subdivided until the estimate of each piece is within `tolerance`.
+    fn arc_length(self, tolerance: Scalar<Self>) -> Scalar<Self> {
+        self.to_cubic::<[Self::Vector; 4]>().arc_length(tolerance)
+    }
+    /// Get the parameter of the point on the curve closest to the given point
+    fn nearest_t(self, point: Self::Vector) -> Scalar<Self> {
+        self.to_cubic::<[Self::Vector; 4]>().nearest_t(point)
+    }
+    /// Get the point on the curve closest to the given point
+    fn nearest_point(self, point: Self::Vector) -> Self::Vector {
+        self.eval(self.nearest_t(point))
+    }
+    /// Get points along the curve, including both endpoints
+    ///
+    /// The curve is adaptively subdivided so that the polyline formed by
+    /// the points deviates from the curve by no more than `tolerance`.
+    fn flatten(self, tolerance: Scalar<Self>) -> Vec<Self::Vector> {
+        self.to_cubic::<[Self::Vector; 4]>().flatten(tolerance)
+    }
+}
+
+impl<T> QuadBezier for T
+where
+    T: Trio + Copy,
+    T::Item: FloatingVector2,
+    <T::Item as Vector2>::Scalar: FloatingScalar,
+{
+    type Vector = T::Item;
+    fn from_control_points([a, b, c]: [Self::Vector; 3]) -> Self {
+        T::from_items(a, b, c)
+    }
+    fn control_points(self) -> [Self::Vector; 3] {
+        let (a, b, c) = self.into_trio();
+        [a, b, c]
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn quad_bezier() {
+    let curve = [[0.0, 0.0], [4.0, 4.0], [4.0, 0.0]];
+    let bounds: [f64; 4] = curve.bounding_rect();
+    assert_eq!([0.0, 0.0], bounds.top_left());
+    assert_eq!(4.0, bounds.right());
+    assert_eq!(2.0, bounds.bottom());
+    let (left, right) = curve.split(0.3);
+    assert_eq!(curve.eval(0.3), left.end());
+    assert_eq!(curve.eval(0.3), right.start());
+    let flat = curve.flatten(0.01);
+    for pair in flat.windows(2) {
+        let mid = pair[0].lerp(pair[1], 0.5);
+        assert!(mid.dist(curve.nearest_point(mid)) <= 0.01);
+    }
+    assert_eq!(curve.start(), curve.nearest_point([-1.0, -1.0]));
+}
@@ -1,6 +1,11 @@
 //! Module for the [`Rectangle`] trait
 
-use crate::{Pair, Scalar as _, Vector2};
+use std::cmp::Ordering;
+
+use crate::{
+    layout::Grid, Align, Anchor, Constraint, Direction, FloatingScalar, FloatingVector2, Layout,
+    Pair, Scalar as _, Vector2,
+};
 
 /// The scalar type of a [`Rectangle`]
 pub type Scalar<T> = <<T as Rectangle>::Vector as Vector2>::Scalar;
@@ -10,6 +15,8 @@ Trait for manipulating axis-aligned rectangles
 
 Because the primary expected use for this crate is in 2D graphics and alignment implementations,
 a coordinate system where the positive Y direction is "down" is assumed.
+For a coordinate system where the positive Y direction is "up", use [`Rectangle::y_up`]
+to get a [`YUp`] view of the rectangle.
 
 # Note
 Methods of the form `abs_*` account for the case where the size is negative.
@@ -316,6 +323,410 @@ pub trait Rectangle: Copy {
                 .add(Self::Vector::new(left + right, top + bottom)),
         )
     }
+    /// Create a new rectangle of a size whose anchor point is at a position
+    ///
+    /// Unsigned scalars underflow if the rectangle would extend left of or above zero,
+    /// so use signed scalars if that can happen.
+    fn anchored(position: Self::Vector, size: Self::Vector, anchor: Anchor) -> Self {
+        let left = anchor
+            .horizontal()
+            .place(position.x(), Scalar::<Self>::ZERO, size.x());
+        let top = anchor
+            .vertical()
+            .place(position.y(), Scalar::<Self>::ZERO, size.y());
+        Self::new(Self::Vector::new(left, top), size)
+    }
+    /// Get one of the rectangle's nine anchor points
+    fn anchor(self, anchor: Anchor) -> Self::Vector {
+        Self::Vector::new(
+            anchor.horizontal().point(self.abs_left(), self.abs_width()),
+            anchor.vertical().point(self.abs_top(), self.abs_height()),
+        )
+    }
+    /// Get the rectangle moved so that it is aligned within a container
+    ///
+    /// The rectangle may be larger than the container, in which case it overhangs it.
+    /// Unsigned scalars underflow if the overhang would extend left of or above zero,
+    /// so use signed scalars if that can happen.
+    fn align_within(self, container: Self, horizontal: Align, vertical: Align) -> Self {
+        let size = self.abs_size();
+        let left = horizontal.place(container.abs_left(), container.abs_width(), size.x());
+        let top = vertical.place(container.abs_top(), container.abs_height(), size.y());
+        Self::new(Self::Vector::new(left, top), size)
+    }
+    /// Get the largest rectangle with the same aspect ratio as a size that fits
+    /// inside this one, centered in it
+    ///
+    /// This leaves empty bars on two sides, as when letterboxing an image.
+    fn fit_contain(self, size: Self::Vector) -> Self {
+        let size = scale_to(size, self.abs_size(), false);
+        Self::new(self.abs_top_left(), size).align_within(self, Align::Center, Align::Center)
+    }
+    /// Get the smallest rectangle with the same aspect ratio as a size that
+    /// covers this one, centered on it
+    ///
+    /// This overhangs two sides, as when cropping an image to fill a frame.
+    fn fit_cover(self, size: Self::Vector) -> Self {
+        let size = scale_to(size, self.abs_size(), true);
+        Self::new(self.abs_top_left(), size).align_within(self, Align::Center, Align::Center)
+    }
+    /// Get the rectangle that is this one with its height changed so
+    /// that its width and height have the ratio `width:height`
+    fn with_aspect_ratio(self, width: Scalar<Self>, height: Scalar<Self>) -> Self {
+        let new_height = if width == Scalar::<Self>::ZERO {
+            Scalar::<Self>::ZERO
+        } else {
+            self.abs_width() * height / width
+        };
+        Self::new(
+            self.abs_top_left(),
+            Self::Vector::new(self.abs_width(), new_height),
+        )
+    }
+    /// Divide the rectangle into nine pieces for drawing a nine-slice image
+    ///
+    /// Insets should be ordered `[left, right, top, bottom]`. They are limited so that
+    /// opposite insets do not overlap. The pieces are ordered row by row, from the
+    /// top-left corner to the bottom-right corner, with the center piece in the middle.
+    fn nine_slice(self, [left, right, top, bottom]: [Scalar<Self>; 4]) -> [Self; 9] {
+        let zero = Scalar::<Self>::ZERO;
+        let (width, height) = (self.abs_width(), self.abs_height());
+        let left = left.maxx(zero).minn(width);
+        let right = right.maxx(zero).minn(width - left);
+        let top = top.maxx(zero).minn(height);
+        let bottom = bottom.maxx(zero).minn(height - top);
+        let xs = [left, width - left - right, right];
+        let ys = [top, height - top - bottom, bottom];
+        let mut pieces = [self; 9];
+        let mut y = self.abs_top();
+        for (row, &piece_height) in ys.iter().enumerate() {
+            let mut x = self.abs_left();
+            for (column, &piece_width) in xs.iter().enumerate() {
+                pieces[row * 3 + column] = Self::new(
+                    Self::Vector::new(x, y),
+                    Self::Vector::new(piece_width, piece_height),
+                );
+                x = x + piece_width;
+            }
+            y = y + piece_height;
+        }
+        pieces
+    }
+    /// Split the rectangle into a left part of the given width and a right part
+    ///
+    /// The width is limited to that of the rectangle.
+    fn split_h(self, width: Scalar<Self>) -> (Self, Self) {
+        let width = width.maxx(Scalar::<Self>::ZERO).minn(self.abs_width());
+        let (top, height) = (self.abs_top(), self.abs_height());
+        let middle = self.abs_left() + width;
+        (
+            Self::new(
+                Self::Vector::new(self.abs_left(), top),
+                Self::Vector::new(width, height),
+            ),
+            Self::new(
+                Self::Vector::new(middle, top),
+                Self::Vector::new(self.abs_width() - width, height),
+            ),
+        )
+    }
+    /// Split the rectangle into a top part of the given height and a bottom part
+    ///
+    /// The height is limited to that of the rectangle.
+    fn split_v(self, height: Scalar<Self>) -> (Self, Self) {
+        let height = height.maxx(Scalar::<Self>::ZERO).minn(self.abs_height());
+        let (left, width) = (self.abs_left(), self.abs_width());
+        let middle = self.abs_top() + height;
+        (
+            Self::new(
+                Self::Vector::new(left, self.abs_top()),
+                Self::Vector::new(width, height),
+            ),
+            Self::new(
+                Self::Vector::new(left, middle),
+                Self::Vector::new(width, self.abs_height() - height),
+            ),
+        )
+    }
+    /// Split the rectangle into left and right parts whose widths have the ratio `left:right`
+    fn split_h_ratio(self, left: Scalar<Self>, right: Scalar<Self>) -> (Self, Self) {
+        self.split_h(ratio_of(self.abs_width(), left, right))
+    }
+    /// Split the rectangle into top and bottom parts whose heights have the ratio `top:bottom`
+    fn split_v_ratio(self, top: Scalar<Self>, bottom: Scalar<Self>) -> (Self, Self) {
+        self.split_v(ratio_of(self.abs_height(), top, bottom))
+    }
+    /// Split the rectangle into `n` equal pieces with gaps between them
+    ///
+    /// Integer pieces that cannot be equal differ by at most 1.
+    fn split_into_n(self, direction: Direction, n: usize, gap: Scalar<Self>) -> Vec<Self> {
+        Layout::new(direction, vec![Constraint::Fill(Scalar::<Self>::ONE); n])
+            .with_gap(gap)
+            .split(self)
+    }
+    /// Divide the rectangle into a grid with gaps between the cells
+    ///
+    /// The cells are iterated row by row, from top-left to bottom-right.
+    fn grid(self, rows: usize, columns: usize, gap: Self::Vector) -> Grid<Self> {
+        Grid::new(
+            self.split_into_n(Direction::Vertical, rows, gap.y()),
+            self.split_into_n(Direction::Horizontal, columns, gap.x()),
+        )
+    }
+    /// Get the parts of the rectangle that are not covered by another
+    ///
+    /// The result is up to four non-overlapping rectangles with non-negative sizes,
+    /// ordered top to bottom and then left to right. If the rectangles do not
+    /// overlap, the result is just this rectangle with its size made non-negative.
+    /// An empty rectangle has no parts.
+    fn subtract(self, other: Self) -> Vec<Self> {
+        let (left, right) = (self.abs_left(), self.abs_right());
+        let (top, bottom) = (self.abs_top(), self.abs_bottom());
+        if left >= right || top >= bottom {
+            return Vec::new();
+        }
+        let (cut_left, cut_right) = (left.maxx(other.abs_left()), right.minn(other.abs_right()));
+        let (cut_top, cut_bottom) = (top.maxx(other.abs_top()), bottom.minn(other.abs_bottom()));
+        if cut_left >= cut_right || cut_top >= cut_bottom {
+            return vec![Self::new(self.abs_top_left(), self.abs_size())];
+        }
+        let rect = |left: Scalar<Self>, top, right, bottom| {
+            Self::new(
+                Self::Vector::new(left, top),
+                Self::Vector::new(right - left, bottom - top),
+            )
+        };
+        let mut pieces = Vec::with_capacity(4);
+        if top < cut_top {
+            pieces.push(rect(left, top, right, cut_top));
+        }
+        if left < cut_left {
+            pieces.push(rect(left, cut_top, cut_left, cut_bottom));
+        }
+        if cut_right < right {
+            pieces.push(rect(cut_right, cut_top, right, cut_bottom));
+        }
+        if cut_bottom < bottom {
+            pieces.push(rect(left, cut_bottom, right, bottom));
+        }
+        pieces
+    }
+    /// Clip a line segment to the part inside the rectangle using the Liang–Barsky algorithm
+    ///
+    /// Returns `None` if the segment is entirely outside
+    fn clip_segment(self, a: Self::Vector, b: Self::Vector) -> Option<[Self::Vector; 2]>
+    where
+        Self::Vector: FloatingVector2,
+        Scalar<Self>: FloatingScalar,
+    {
+        let zero = Scalar::<Self>::ZERO;
+        let delta = b.sub(a);
+        let (mut enter, mut exit) = (zero, Scalar::<Self>::ONE);
+        // Each boundary is a constraint `p * t <= q` on the position `t` along the segment
+        for &(p, q) in &[
+            (-delta.x(), a.x() - self.abs_left()),
+            (delta.x(), self.abs_right() - a.x()),
+            (-delta.y(), a.y() - self.abs_top()),
+            (delta.y(), self.abs_bottom() - a.y()),
+        ] {
+            if p.is_zero() {
+                if q < zero {
+                    return None;
+                }
+            } else if p < zero {
+                enter = enter.maxx(q / p);
+            } else {
+                exit = exit.minn(q / p);
+            }
+        }
+        if enter > exit {
+            return None;
+        }
+        Some([
+            self.clamp_point(a.lerp(b, enter)),
+            self.clamp_point(a.lerp(b, exit)),
+        ])
+    }
+    /// Clip a line segment to the part inside the rectangle using the Cohen–Sutherland algorithm
+    ///
+    /// This gives the same result as [`Rectangle::clip_segment`], but it is
+    /// faster when most segments are entirely inside or outside.
+    fn clip_segment_cohen_sutherland(
+        self,
+        mut a: Self::Vector,
+        mut b: Self::Vector,
+    ) -> Option<[Self::Vector; 2]>
+    where
+        Self::Vector: FloatingVector2,
+        Scalar<Self>: FloatingScalar,
+    {
+        let (left, right) = (self.abs_left(), self.abs_right());
+        let (top, bottom) = (self.abs_top(), self.abs_bottom());
+        let (to_left, to_right, to_top, to_bottom) = (1, 2, 4, 8);
+        let outcode = |p: Self::Vector| {
+            let mut code = 0;
+            if p.x() < left {
+                code |= to_left;
+            } else if p.x() > right {
+                code |= to_right;
+            }
+            if p.y() < top {
+                code |= to_top;
+            } else if p.y() > bottom {
+                code |= to_bottom;
+            }
+            code
+        };
+        let (mut code_a, mut code_b) = (outcode(a), outcode(b));
+        loop {
+            if code_a | code_b == 0 {
+                return Some([a, b]);
+            }
+            if code_a & code_b != 0 {
+                return None;
+            }
+            // Move an outside end point onto the boundary it is beyond
+            let code = code_a.max(code_b);
+            let (from, to) = if code == code_a { (a, b) } else { (b, a) };
+            let at_y = |y: Scalar<Self>| {
+                let t = (y - from.y()) / (to.y() - from.y());
+                Self::Vector::new(from.x() + (to.x() - from.x()) * t, y)
+            };
+            let at_x = |x: Scalar<Self>| {
+                let t = (x - from.x()) / (to.x() - from.x());
+                Self::Vector::new(x, from.y() + (to.y() - from.y()) * t)
+            };
+            let moved = if code & to_bottom != 0 {
+                at_y(bottom)
+            } else if code & to_top != 0 {
+                at_y(top)
+            } else if code & to_right != 0 {
+                at_x(right)
+            } else {
+                at_x(left)
+            };
+            if code == code_a {
+                a = moved;
+                code_a = outcode(a);
+            } else {
+                b = moved;
+                code_b = outcode(b);
+            }
+        }
+    }
+    /// Clip a polygon to the part inside the rectangle using the Sutherland–Hodgman algorithm
+    ///
+    /// If the polygon is concave, the result may have edges along the
+    /// rectangle's boundary that connect its separate parts.
+    fn clip_polygon(self, polygon: &[Self::Vector]) -> Vec<Self::Vector>
+    where
+        Self::Vector: FloatingVector2,
+        Scalar<Self>: FloatingScalar,
+    {
+        let polygon = clip_axis(polygon, self.abs_left(), false, false);
+        let polygon = clip_axis(&polygon, self.abs_right(), false, true);
+        let polygon = clip_axis(&polygon, self.abs_top(), true, false);
+        clip_axis(&polygon, self.abs_bottom(), true, true)
+    }
+    /// Get the point in the rectangle that is closest to the given point
+    fn clamp_point(self, point: Self::Vector) -> Self::Vector {
+        Self::Vector::new(
+            point.x().maxx(self.abs_left()).minn(self.abs_right()),
+            point.y().maxx(self.abs_top()).minn(self.abs_bottom()),
+        )
+    }
+    /// Get a view of this rectangle in a coordinate system where the positive Y direction is "up"
+    fn y_up(self) -> YUp<Self> {
+        YUp(self)
+    }
+}
+
+/// Scale a size to fit inside or cover a container while keeping its aspect ratio
+///
+/// Sizes are compared by cross-multiplying so that this works for integers.
+fn scale_to<V>(size: V, container: V, cover: bool) -> V
+where
+    V: Vector2,
+{
+    let zero = V::Scalar::ZERO;
+    let (width, height) = (size.x().abs(), size.y().abs());
+    // Whether the size is relatively taller than the container
+    let taller = width * container.y() <= height * container.x();
+    if taller != cover {
+        let width = if height == zero {
+            zero
+        } else {
+            width * container.y() / height
+        };
+        V::new(width, container.y())
+    } else {
+        let height = if width == zero {
+            zero
+        } else {
+            height * container.x() / width
+        };
+        V::new(container.x(), height)
+    }
+}
+
+/// Get the part of a length given by the ratio `a:b`
+fn ratio_of<S>(length: S, a: S, b: S) -> S
+where
+    S: crate::Scalar,
+{
+    let total = a.maxx(S::ZERO) + b.maxx(S::ZERO);
+    if total == S::ZERO {
+        S::ZERO
+    } else {
+        length * a.maxx(S::ZERO) / total
+    }
+}
+
+/// Clip a polygon to one side of a horizontal or vertical line
+///
+/// If `below` is true, the part with coordinates less than or equal to the bound is kept.
+fn clip_axis<V>(polygon: &[V], bound: V::Scalar, horizontal: bool, below: bool) -> Vec<V>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    // Get a point's coordinates along and across the line
+    let split = |v: V| {
+        if horizontal {
+            (v.y(), v.x())
+        } else {
+            (v.x(), v.y())
+        }
+    };
+    // Get which side of the line a point is on, where `Greater` is outside
+    let side = |v: V| {
+        let order = split(v).0.partial_cmp(&bound).unwrap_or(Ordering::Equal);
+        if below {
+            order
+        } else {
+            order.reverse()
+        }
+    };
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        let (side_a, side_b) = (side(a), side(b));
+        if side_a != Ordering::Greater {
+            clipped.push(a);
+        }
+        // Points on the line are kept as they are
+        if side_a != Ordering::Equal && side_b != Ordering::Equal && side_a != side_b {
+            // The crossing point is exactly on the line
+            let ((a_along, a_across), (b_along, b_across)) = (split(a), split(b));
+            let across = a_across + (b_across - a_across) * (bound - a_along) / (b_along - a_along);
+            clipped.push(if horizontal {
+                V::new(across, bound)
+            } else {
+                V::new(bound, across)
+            });
+        }
+    }
+    clipped
 }
 
 impl<P> Rectangle for P
@@ -334,3 +745,209 @@ where
         self.into_pair().1
     }
 }
+
+/**
+A view of a [`Rectangle`] in a coordinate system where the positive Y direction is "up"
+
+The wrapped rectangle is unchanged. Its stored position is the corner with the lowest
+coordinates, which is the bottom-left corner when Y is up. Only the methods whose meaning
+depends on the direction of the Y axis are provided. Everything else can be accessed
+through the wrapped rectangle.
+
+```
+use vector2math::*;
+
+let rect = [1, 2, 3, 4].y_up();
+assert_eq!(6, rect.top());
+assert_eq!(2, rect.bottom());
+assert_eq!([1, 6], rect.top_left());
+assert_eq!([1, 2], rect.bottom_left());
+assert_eq!([1, 2, 3, 5], rect.with_top(7).0);
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct YUp<R>(pub R);
+
+impl<R> YUp<R>
+where
+    R: Rectangle,
+{
+    /// Create a new rectangle from a bottom-left corner position and a size
+    pub fn new(bottom_left: R::Vector, size: R::Vector) -> Self {
+        YUp(R::new(bottom_left, size))
+    }
+    /// Get the top y
+    pub fn top(self) -> Scalar<R> {
+        self.0.bottom()
+    }
+    /// Get the bottom y
+    pub fn bottom(self) -> Scalar<R> {
+        self.0.top()
+    }
+    /// Get the absolute top y
+    pub fn abs_top(self) -> Scalar<R> {
+        self.0.abs_bottom()
+    }
+    /// Get the absolute bottom y
+    pub fn abs_bottom(self) -> Scalar<R> {
+        self.0.abs_top()
+    }
+    /// Get the top-left corner position
+    pub fn top_left(self) -> R::Vector {
+        self.0.bottom_left()
+    }
+    /// Get the top-right corner position
+    pub fn top_right(self) -> R::Vector {
+        self.0.bottom_right()
+    }
+    /// Get the bottom-left corner position
+    pub fn bottom_left(self) -> R::Vector {
+        self.0.top_left()
+    }
+    /// Get the bottom-right corner position
+    pub fn bottom_right(self) -> R::Vector {
+        self.0.top_right()
+    }
+    /// Get the absolute top-left corner position
+    pub fn abs_top_left(self) -> R::Vector {
+        self.0.abs_bottom_left()
+    }
+    /// Get the absolute top-right corner position
+    pub fn abs_top_right(self) -> R::Vector {
+        self.0.abs_bottom_right()
+    }
+    /// Get the absolute bottom-left corner position
+    pub fn abs_bottom_left(self) -> R::Vector {
+        self.0.abs_top_left()
+    }
+    /// Get the absolute bottom-right corner position
+    pub fn abs_bottom_right(self) -> R::Vector {
+        self.0.abs_top_right()
+    }
+    /// Get an array the rectangle's four corners, clockwise from top-left
+    pub fn corners(self) -> [R::Vector; 4] {
+        [
+            self.top_left(),
+            self.top_right(),
+            self.bottom_right(),
+            self.bottom_left(),
+        ]
+    }
+    /// Get the rectangle that is this one with a different top bound
+    pub fn with_top(self, top: Scalar<R>) -> Self {
+        YUp(self.0.with_bottom(top))
+    }
+    /// Get the rectangle that is this one with a different bottom bound
+    pub fn with_bottom(self, bottom: Scalar<R>) -> Self {
+        YUp(self.0.with_top(bottom))
+    }
+    /// Get the rectangle that is inside this one with the given
+    /// margin on all sides
+    pub fn inner_margin(self, margin: Scalar<R>) -> Self {
+        YUp(self.0.inner_margin(margin))
+    }
+    /// Get the rectangle that is inside this one with the given margins
+    ///
+    /// Margins should be ordered `[left, right, top, bottom]`
+    pub fn inner_margins(self, [left, right, top, bottom]: [Scalar<R>; 4]) -> Self {
+        YUp(self.0.inner_margins([left, right, bottom, top]))
+    }
+    /// Get the rectangle that is outside this one with the given
+    /// margin on all sides
+    pub fn outer_margin(self, margin: Scalar<R>) -> Self {
+        YUp(self.0.outer_margin(margin))
+    }
+    /// Get the rectangle that is outside this one with the given margins
+    ///
+    /// Margins should be ordered `[left, right, top, bottom]`
+    pub fn outer_margins(self, [left, right, top, bottom]: [Scalar<R>; 4]) -> Self {
+        YUp(self.0.outer_margins([left, right, bottom, top]))
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn clipping() {
+    // The size is negative, so the rectangle spans from 0 to 4 on both axes
+    let rect = [4.0, 4.0, -4.0, -4.0];
+    let (a, b) = ([-2.0, 1.0], [6.0, 3.0]);
+    assert_eq!(Some([[0.0, 1.5], [4.0, 2.5]]), rect.clip_segment(a, b));
+    assert_eq!(
+        Some([[0.0, 1.5], [4.0, 2.5]]),
+        rect.clip_segment_cohen_sutherland(a, b)
+    );
+    assert_eq!(None, rect.clip_segment([-1.0, 5.0], [5.0, 6.0]));
+    assert_eq!(
+        None,
+        rect.clip_segment_cohen_sutherland([-1.0, 5.0], [5.0, 6.0])
+    );
+    let inside = [[1.0, 1.0], [2.0, 3.0]];
+    assert_eq!(Some(inside), rect.clip_segment(inside[0], inside[1]));
+    // A triangle poking out of two sides
+    let triangle = [[2.0, 2.0], [6.0, 2.0], [2.0, -2.0]];
+    assert_eq!(
+        vec![[2.0, 2.0], [4.0, 2.0], [4.0, 0.0], [2.0, 0.0]],
+        rect.clip_polygon(&triangle)
+    );
+    assert!(rect
+        .clip_polygon(&[[5.0, 5.0], [6.0, 5.0], [5.0, 6.0]])
+        .is_empty());
+}
+
+#[cfg(test)]
+#[test]
+fn subtract() {
+    // A hole in the middle leaves a piece on each side
+    let pieces = [0, 0, 10, 10].subtract([2, 3, 4, 5]);
+    assert_eq!(
+        vec![[0, 0, 10, 3], [0, 3, 2, 5], [6, 3, 4, 5], [0, 8, 10, 2]],
+        pieces
+    );
+    assert_eq!(
+        vec![[0, 0, 10, 3]],
+        [0, 0, 10, 10].subtract([-5, 3, 20, 20])
+    );
+    assert!([0, 0, 10, 10].subtract([-5, -5, 20, 20]).is_empty());
+    assert_eq!(vec![[0, 0, 5, 5]], [0, 0, 5, 5].subtract([5, 0, 5, 5]));
+    assert_eq!(vec![[0, 0, 5, 5]], [5, 5, -5, -5].subtract([5, 0, 5, 5]));
+    assert!([0, 0, 0, 5].subtract([5, 0, 5, 5]).is_empty());
+}
+
+#[cfg(test)]
+#[test]
+fn alignment() {
+    let screen = [0, 0, 100, 50];
+    let dialog = [7, 7, 40, 20];
+    assert_eq!(
+        [30, 15, 40, 20],
+        dialog.align_within(screen, Align::Center, Align::Center)
+    );
+    assert_eq!(
+        [60, 0, 40, 20],
+        dialog.align_within(screen, Align::End, Align::Start)
+    );
+    assert_eq!([50, 50], screen.anchor(Anchor::Bottom));
+    assert_eq!(
+        [60, 30, 40, 20],
+        <[i32; 4]>::anchored([100, 50], [40, 20], Anchor::BottomRight)
+    );
+    for &anchor in &[Anchor::TopLeft, Anchor::Center, Anchor::Right] {
+        let rect = <[u32; 4]>::anchored([50, 50], [10, 20], anchor);
+        assert_eq!([50, 50], rect.anchor(anchor));
+        assert_eq!(anchor, Anchor::new(anchor.horizontal(), anchor.vertical()));
+    }
+    // Fitting a 4:3 image into a 16:9 screen
+    let screen = [0.0, 0.0, 1600.0, 900.0];
+    assert_eq!([200.0, 0.0, 1200.0, 900.0], screen.fit_contain([4.0, 3.0]));
+    assert_eq!([0.0, -150.0, 1600.0, 1200.0], screen.fit_cover([4.0, 3.0]));
+    assert_eq!([0, 0, 160, 90], [0, 0, 160, 1].with_aspect_ratio(16, 9));
+    assert_eq!([0, 2, 10, 5], [0, 0, 10, 10].fit_contain([4, 2]));
+    // Nine-slice pieces tile the rectangle
+    let pieces = [0, 0, 10, 8].nine_slice([2, 3, 1, 1]);
+    assert_eq!([0, 0, 2, 1], pieces[0]);
+    assert_eq!([2, 1, 5, 6], pieces[4]);
+    assert_eq!([7, 7, 3, 1], pieces[8]);
+    assert_eq!(80, pieces.iter().map(|piece| piece.area()).sum::<i32>());
+    // Insets that are too large are limited
+    assert_eq!([3, 0, 1, 8], [0, 0, 4, 8].nine_slice([3, 3, 0, 0])[5]);
+}
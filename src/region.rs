@@ -0,0 +1,312 @@
+//! Module for the [`Region`] type
+
+use std::{cmp::Ordering, iter::FromIterator};
+
+use crate::{rectangle::Scalar, BooleanOp, Rectangle, Scalar as _, Vector2};
+
+/**
+An area made of disjoint axis-aligned rectangles
+
+Like the regions of X11 and Pixman, the rectangles are stored in bands.
+All of the rectangles in a band have the same top and bottom, and they
+are sorted left to right without touching. Bands are sorted top to bottom,
+and adjacent bands that cover the same horizontal spans are merged. This
+means that two regions that cover the same area have the same rectangles.
+
+Rectangles in a region include their top and left edges but not their bottom
+and right edges, so that rectangles of whole pixels do not overlap.
+```
+use vector2math::*;
+
+let mut damage = Region::from([0, 0, 10, 10]);
+damage = damage.union(&Region::from([5, 5, 10, 10]));
+assert_eq!(3, damage.bands().count());
+assert_eq!(175, damage.area());
+assert!(damage.contains([12, 12]));
+assert!(!damage.contains([12, 2]));
+
+let visible = damage.subtract(&Region::from([0, 0, 8, 20]));
+assert_eq!(&[[8, 0, 2, 5], [8, 5, 7, 10]], visible.rects());
+assert!(damage.contains_rect([8, 5, 7, 10]));
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Region<R> {
+    rects: Vec<R>,
+}
+
+impl<R> Default for Region<R> {
+    fn default() -> Self {
+        Region { rects: Vec::new() }
+    }
+}
+
+impl<R> Region<R>
+where
+    R: Rectangle,
+{
+    /// Create a new empty region
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Get the region's rectangles, ordered top to bottom and then left to right
+    ///
+    /// Every rectangle has a positive size.
+    pub fn rects(&self) -> &[R] {
+        &self.rects
+    }
+    /// Iterate over the region's rectangles
+    pub fn iter(&self) -> std::slice::Iter<'_, R> {
+        self.rects.iter()
+    }
+    /// Iterate over the region's bands, each of which is a list of
+    /// rectangles with the same top and bottom
+    pub fn bands(&self) -> impl Iterator<Item = &[R]> {
+        let mut rest = &self.rects[..];
+        std::iter::from_fn(move || {
+            let top = rest.first()?.top();
+            let len = rest
+                .iter()
+                .position(|rect| rect.top() != top)
+                .unwrap_or(rest.len());
+            let (band, tail) = rest.split_at(len);
+            rest = tail;
+            Some(band)
+        })
+    }
+    /// Check if the region is empty
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+    /// Get the total area of the region
+    pub fn area(&self) -> Scalar<R> {
+        self.rects
+            .iter()
+            .fold(Scalar::<R>::ZERO, |sum, rect| sum + rect.area())
+    }
+    /// Get the smallest rectangle that contains the region
+    ///
+    /// Returns `None` if the region is empty
+    pub fn bounds(&self) -> Option<R> {
+        let first = self.rects.first()?;
+        let last = self.rects[self.rects.len() - 1];
+        let (left, right) = self
+            .rects
+            .iter()
+            .fold((first.left(), first.right()), |(left, right), rect| {
+                (left.minn(rect.left()), right.maxx(rect.right()))
+            });
+        Some(R::new(
+            R::Vector::new(left, first.top()),
+            R::Vector::new(right - left, last.bottom() - first.top()),
+        ))
+    }
+    /// Check if the region contains a point
+    pub fn contains(&self, point: R::Vector) -> bool {
+        match self
+            .bands()
+            .find(|band| band[0].top() <= point.y() && point.y() < band[0].bottom())
+        {
+            Some(band) => band
+                .iter()
+                .any(|rect| rect.left() <= point.x() && point.x() < rect.right()),
+            None => false,
+        }
+    }
+    /// Check if the region contains all of a rectangle
+    pub fn contains_rect(&self, rect: R) -> bool {
+        Region::from(rect).subtract(self).is_empty()
+    }
+    /// Get the region moved by an offset
+    pub fn translated(&self, offset: R::Vector) -> Self {
+        Region {
+            rects: self
+                .rects
+                .iter()
+                .map(|rect| rect.translated(offset))
+                .collect(),
+        }
+    }
+    /// Get the area covered by either region
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, BooleanOp::Union)
+    }
+    /// Get the area covered by both regions
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, BooleanOp::Intersection)
+    }
+    /// Get the area covered by this region but not another
+    pub fn subtract(&self, other: &Self) -> Self {
+        self.combine(other, BooleanOp::Difference)
+    }
+    /// Get the area covered by exactly one of the regions
+    pub fn xor(&self, other: &Self) -> Self {
+        self.combine(other, BooleanOp::Xor)
+    }
+    /// Combine two regions
+    pub fn combine(&self, other: &Self, op: BooleanOp) -> Self {
+        let compare = |a: &Scalar<R>, b: &Scalar<R>| a.partial_cmp(b).unwrap_or(Ordering::Equal);
+        let mut ys: Vec<Scalar<R>> = self
+            .rects
+            .iter()
+            .chain(&other.rects)
+            .flat_map(|rect| [rect.top(), rect.bottom()])
+            .collect();
+        ys.sort_by(compare);
+        ys.dedup();
+        let (mut ours, mut theirs) = (Bands::new(&self.rects), Bands::new(&other.rects));
+        let mut bands = Vec::<(Scalar<R>, Scalar<R>, Vec<_>)>::new();
+        for pair in ys.windows(2) {
+            let (top, bottom) = (pair[0], pair[1]);
+            let spans = combine_spans(ours.spans_at(top), theirs.spans_at(top), op);
+            if spans.is_empty() {
+                continue;
+            }
+            match bands.last_mut() {
+                // Merge with the band above if it covers the same spans
+                Some(last) if last.1 == top && last.2 == spans => last.1 = bottom,
+                _ => bands.push((top, bottom, spans)),
+            }
+        }
+        let rects = bands
+            .into_iter()
+            .flat_map(|(top, bottom, spans)| {
+                spans.into_iter().map(move |[left, right]| {
+                    R::new(
+                        R::Vector::new(left, top),
+                        R::Vector::new(right - left, bottom - top),
+                    )
+                })
+            })
+            .collect();
+        Region { rects }
+    }
+}
+
+impl<R> From<R> for Region<R>
+where
+    R: Rectangle,
+{
+    fn from(rect: R) -> Self {
+        let rects = if rect.abs_left() < rect.abs_right() && rect.abs_top() < rect.abs_bottom() {
+            vec![R::new(rect.abs_top_left(), rect.abs_size())]
+        } else {
+            Vec::new()
+        };
+        Region { rects }
+    }
+}
+
+impl<R> FromIterator<R> for Region<R>
+where
+    R: Rectangle,
+{
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut region = Region::new();
+        region.extend(iter);
+        region
+    }
+}
+
+impl<R> Extend<R> for Region<R>
+where
+    R: Rectangle,
+{
+    fn extend<I: IntoIterator<Item = R>>(&mut self, iter: I) {
+        for rect in iter {
+            *self = self.union(&Region::from(rect));
+        }
+    }
+}
+
+impl<'a, R> IntoIterator for &'a Region<R>
+where
+    R: Rectangle,
+{
+    type Item = &'a R;
+    type IntoIter = std::slice::Iter<'a, R>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A cursor over the bands of a region's rectangles, moving down
+struct Bands<'a, R> {
+    rects: &'a [R],
+    start: usize,
+}
+
+impl<'a, R> Bands<'a, R>
+where
+    R: Rectangle,
+{
+    fn new(rects: &'a [R]) -> Self {
+        Bands { rects, start: 0 }
+    }
+    /// Get the left and right of each rectangle in the band at a height,
+    /// which must not be above that of the previous call
+    fn spans_at(&mut self, y: Scalar<R>) -> Vec<[Scalar<R>; 2]> {
+        while self.start < self.rects.len() && self.rects[self.start].bottom() <= y {
+            self.start += 1;
+        }
+        self.rects[self.start..]
+            .iter()
+            .take_while(|rect| rect.top() <= y)
+            .map(|rect| [rect.left(), rect.right()])
+            .collect()
+    }
+}
+
+/// Combine two sorted lists of disjoint spans
+fn combine_spans<S>(ours: Vec<[S; 2]>, theirs: Vec<[S; 2]>, op: BooleanOp) -> Vec<[S; 2]>
+where
+    S: crate::Scalar,
+{
+    let mut xs: Vec<S> = ours.iter().chain(&theirs).flatten().copied().collect();
+    xs.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    xs.dedup();
+    let inside = |spans: &[[S; 2]], x: S| spans.iter().any(|&[left, right]| left <= x && x < right);
+    let mut combined: Vec<[S; 2]> = Vec::new();
+    for pair in xs.windows(2) {
+        let (left, right) = (pair[0], pair[1]);
+        if !op.apply(inside(&ours, left), inside(&theirs, left)) {
+            continue;
+        }
+        match combined.last_mut() {
+            Some(last) if last[1] == left => last[1] = right,
+            _ => combined.push([left, right]),
+        }
+    }
+    combined
+}
+
+#[cfg(test)]
+#[test]
+fn region() {
+    // Two overlapping squares
+    let a = Region::from([0, 0, 4, 4]);
+    let b = Region::from([2, 2, 4, 4]);
+    let union = a.union(&b);
+    assert_eq!(&[[0, 0, 4, 2], [0, 2, 6, 2], [2, 4, 4, 2]], union.rects());
+    assert_eq!(Some([0, 0, 6, 6]), union.bounds());
+    assert_eq!(&[[2, 2, 2, 2]], a.intersection(&b).rects());
+    assert_eq!(24, a.xor(&b).area());
+    assert_eq!(12, a.subtract(&b).area());
+    // Subtracting the other parts leaves the original
+    assert_eq!(a, union.subtract(&b.subtract(&a)));
+    // Adjacent bands with the same spans merge
+    let stacked: Region<[i32; 4]> = vec![[0, 0, 3, 1], [0, 1, 3, 1], [0, 2, 3, 1]]
+        .into_iter()
+        .collect();
+    assert_eq!(&[[0, 0, 3, 3]], stacked.rects());
+    // Edges on the bottom and right are not included
+    assert!(stacked.contains([0, 0]));
+    assert!(!stacked.contains([3, 0]));
+    assert!(!stacked.contains([0, 3]));
+    // Negative sizes are normalized and empty rectangles are ignored
+    assert_eq!(Region::from([0, 0, 4, 4]), Region::from([4, 4, -4, -4]));
+    assert!(Region::from([1, 1, 0, 5]).is_empty());
+    assert!(union.contains_rect([1, 1, 3, 3]));
+    assert!(!union.contains_rect([1, 1, 5, 4]));
+    assert!(union.contains_rect([9, 9, 0, 0]));
+}
@@ -0,0 +1,248 @@
+//! Module for the [`RoundedRect`] trait
+
+use crate::{
+    capsule::closest_on_segment, circle::arc_points, FloatingScalar, FloatingVector2, Rectangle,
+    Scalar as _, Vector2,
+};
+
+/// The scalar type of a [`RoundedRect`]
+pub type Scalar<T> = <<T as RoundedRect>::Vector as Vector2>::Scalar;
+
+/**
+Trait for manipulating axis-aligned rectangles with rounded corners
+
+A rounded rectangle is defined by a [`Rectangle`] and the radius of each of its corners.
+Radii are ordered `[top_left, top_right, bottom_right, bottom_left]`, the same as
+[`Rectangle::corners`].
+
+If the radii of two adjacent corners add up to more than the length of the side
+between them, all radii are scaled down proportionally until they fit.
+
+```
+use vector2math::*;
+
+let button = ([0.0, 0.0, 10.0, 4.0], [2.0; 4]);
+assert!(button.contains([5.0, 0.0]));
+assert!(!button.contains([0.2, 0.2]));
+assert_eq!(-1.0, button.signed_distance([5.0, 1.0]));
+assert_eq!(1.0, button.signed_distance([2.0, -1.0]));
+```
+*/
+pub trait RoundedRect: Copy
+where
+    Scalar<Self>: FloatingScalar,
+{
+    /// The rectangle type
+    type Rect: Rectangle<Vector = Self::Vector>;
+    /// The vector type
+    type Vector: FloatingVector2;
+    /// Create a new rounded rectangle from a rectangle and the radius of each corner
+    fn new(rect: Self::Rect, radii: [Scalar<Self>; 4]) -> Self;
+    /// Get the rectangle
+    fn rect(self) -> Self::Rect;
+    /// Get the radius of each corner, as given
+    fn radii(self) -> [Scalar<Self>; 4];
+    /// Create a new rounded rectangle with the same radius for each corner
+    fn uniform(rect: Self::Rect, radius: Scalar<Self>) -> Self {
+        Self::new(rect, [radius; 4])
+    }
+    /// Transform the rounded rectangle into one with a different rectangle
+    fn with_rect(self, rect: Self::Rect) -> Self {
+        Self::new(rect, self.radii())
+    }
+    /// Transform the rounded rectangle into one with different radii
+    fn with_radii(self, radii: [Scalar<Self>; 4]) -> Self {
+        Self::new(self.rect(), radii)
+    }
+    /// Get the radius of each corner after scaling them to fit the rectangle
+    fn effective_radii(self) -> [Scalar<Self>; 4] {
+        let zero = Scalar::<Self>::ZERO;
+        let one = Scalar::<Self>::ONE;
+        let size = self.rect().abs_size();
+        let [tl, tr, br, bl] = self.radii();
+        let radii = [tl.maxx(zero), tr.maxx(zero), br.maxx(zero), bl.maxx(zero)];
+        let fit = |side: Scalar<Self>, a: Scalar<Self>, b: Scalar<Self>| {
+            if a + b > side {
+                side / (a + b)
+            } else {
+                one
+            }
+        };
+        let scale = fit(size.x(), radii[0], radii[1])
+            .minn(fit(size.y(), radii[1], radii[2]))
+            .minn(fit(size.x(), radii[2], radii[3]))
+            .minn(fit(size.y(), radii[3], radii[0]));
+        [
+            radii[0] * scale,
+            radii[1] * scale,
+            radii[2] * scale,
+            radii[3] * scale,
+        ]
+    }
+    /// Get the center of the circle that forms each rounded corner
+    ///
+    /// Corners are ordered the same as [`Rectangle::corners`]
+    fn corner_centers(self) -> [Self::Vector; 4] {
+        let rect = self.rect();
+        let [tl, tr, br, bl] = self.effective_radii();
+        let (left, right) = (rect.abs_left(), rect.abs_right());
+        let (top, bottom) = (rect.abs_top(), rect.abs_bottom());
+        [
+            Self::Vector::new(left + tl, top + tl),
+            Self::Vector::new(right - tr, top + tr),
+            Self::Vector::new(right - br, bottom - br),
+            Self::Vector::new(left + bl, bottom - bl),
+        ]
+    }
+    /// Get the perimeter
+    fn perimeter(self) -> Scalar<Self> {
+        let two = Scalar::<Self>::TWO;
+        let [tl, tr, br, bl] = self.effective_radii();
+        let corner_loss = two - Scalar::<Self>::PI / two;
+        self.rect().abs_size().sum() * two - (tl + tr + br + bl) * corner_loss
+    }
+    /// Get the area
+    fn area(self) -> Scalar<Self> {
+        let four = Scalar::<Self>::TWO + Scalar::<Self>::TWO;
+        let [tl, tr, br, bl] = self.effective_radii();
+        let corner_loss = (four - Scalar::<Self>::PI) / four;
+        self.rect().abs_size().product()
+            - (tl.square() + tr.square() + br.square() + bl.square()) * corner_loss
+    }
+    /// Get the smallest axis-aligned rectangle that contains the rounded rectangle
+    fn bounding_rect(self) -> Self::Rect {
+        let rect = self.rect();
+        Self::Rect::new(rect.abs_top_left(), rect.abs_size())
+    }
+    /// Get the signed distance from the rounded rectangle's boundary to a point
+    ///
+    /// The distance is negative if the point is inside the rounded rectangle.
+    fn signed_distance(self, point: Self::Vector) -> Scalar<Self> {
+        let zero = Scalar::<Self>::ZERO;
+        let rect = self.rect();
+        let radii = self.effective_radii();
+        let centers = self.corner_centers();
+        let (left, right) = (rect.abs_left(), rect.abs_right());
+        let (top, bottom) = (rect.abs_top(), rect.abs_bottom());
+        let edges = [
+            (centers[0].with_y(top), centers[1].with_y(top)),
+            (centers[1].with_x(right), centers[2].with_x(right)),
+            (centers[2].with_y(bottom), centers[3].with_y(bottom)),
+            (centers[3].with_x(left), centers[0].with_x(left)),
+        ];
+        let mut dist = edges
+            .iter()
+            .fold(Scalar::<Self>::infinity(), |dist, &(a, b)| {
+                dist.minn(point.dist(closest_on_segment(point, a, b)))
+            });
+        let mut inside = rect.contains(point);
+        // Each corner's region is the part of the rectangle beyond its circle's center
+        for (i, (center, radius)) in centers.iter().zip(&radii).enumerate() {
+            let offset = point.sub(*center);
+            let in_x = if i == 1 || i == 2 {
+                offset.x() >= zero
+            } else {
+                offset.x() <= zero
+            };
+            let in_y = if i >= 2 {
+                offset.y() >= zero
+            } else {
+                offset.y() <= zero
+            };
+            if in_x && in_y {
+                dist = dist.minn((offset.mag() - *radius).abs());
+                inside = inside && offset.mag() <= *radius;
+            }
+        }
+        if inside {
+            -dist
+        } else {
+            dist
+        }
+    }
+    /// Check that the rounded rectangle contains the given point. Includes edges.
+    fn contains(self, point: Self::Vector) -> bool {
+        self.signed_distance(point) <= Scalar::<Self>::ZERO
+    }
+    /// Check that the rounded rectangle contains all points
+    fn contains_all<I>(self, points: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        points.into_iter().all(|point| self.contains(point))
+    }
+    /// Check that the rounded rectangle contains any point
+    fn contains_any<I>(self, points: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        points.into_iter().any(|point| self.contains(point))
+    }
+    /// Get points along the rounded rectangle's boundary
+    ///
+    /// The points are ordered clockwise from the top-left corner in a coordinate system
+    /// where the positive Y direction is "down". They are spaced so that the polygon they
+    /// form deviates from the rounded rectangle by no more than `tolerance`.
+    fn outline(self, tolerance: Scalar<Self>) -> Vec<Self::Vector> {
+        let half_pi = Scalar::<Self>::PI / Scalar::<Self>::TWO;
+        let mut start = Scalar::<Self>::PI;
+        let mut points = Vec::new();
+        for (center, radius) in self
+            .corner_centers()
+            .iter()
+            .zip(self.effective_radii().iter())
+        {
+            if radius.is_zero() {
+                points.push(*center);
+            } else {
+                points.extend(arc_points(*center, *radius, start, half_pi, tolerance));
+            }
+            start = start + half_pi;
+        }
+        points
+    }
+}
+
+impl<R, S> RoundedRect for (R, [S; 4])
+where
+    R: Rectangle,
+    R::Vector: FloatingVector2<Scalar = S>,
+    S: FloatingScalar,
+{
+    type Rect = R;
+    type Vector = R::Vector;
+    fn new(rect: Self::Rect, radii: [Scalar<Self>; 4]) -> Self {
+        (rect, radii)
+    }
+    fn rect(self) -> Self::Rect {
+        self.0
+    }
+    fn radii(self) -> [Scalar<Self>; 4] {
+        self.1
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn rounded_rect() {
+    let rrect = ([0.0, 0.0, 10.0, 6.0], [0.0, 2.0, 4.0, 6.0]);
+    assert_eq!([0.0, 2.0, 4.0, 6.0], rrect.effective_radii());
+    assert_eq!([1.5; 4], ([0.0, 0.0, 4.0, 3.0], [4.0; 4]).effective_radii());
+    assert_eq!(
+        [[0.0, 0.0], [8.0, 2.0], [6.0, 2.0], [6.0, 0.0]],
+        rrect.corner_centers()
+    );
+    let expected_area = 60.0 - (4.0 + 16.0 + 36.0) * (4.0 - f64::PI) / 4.0;
+    assert!((rrect.area() - expected_area).abs() < 1e-12);
+    assert!((rrect.perimeter() - (32.0 - 12.0 * (2.0 - f64::PI / 2.0))).abs() < 1e-12);
+    assert_eq!(0.0, rrect.signed_distance([0.0, 0.0]));
+    assert_eq!(-1.0, rrect.signed_distance([3.0, 1.0]));
+    assert_eq!(1.0, rrect.signed_distance([6.0, 7.0]));
+    assert!((rrect.signed_distance([10.0, 0.0]) - (8f64.sqrt() - 2.0)).abs() < 1e-12);
+    assert!(!rrect.contains([1.0, 5.5]));
+    let outline = rrect.outline(0.01);
+    assert_eq!([0.0, 0.0], outline[0]);
+    for point in outline {
+        assert!(rrect.signed_distance(point).abs() < 1e-12);
+    }
+}
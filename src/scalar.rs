@@ -1,4 +1,7 @@
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::{
+    cmp::Ordering,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
 
 /// Trait for math with scalar numbers
 pub trait Scalar:
@@ -40,6 +43,17 @@ pub trait Scalar:
             other
         }
     }
+    /// Get the orientation of the path `a -> b -> c` in a Y-up coordinate system
+    ///
+    /// `Greater` is a left turn, `Less` is a right turn, and `Equal` means the points are collinear.
+    /// The default implementation avoids subtraction so that it is exact for
+    /// integers, including unsigned ones, as long as it does not overflow.
+    /// Floats use an adaptive exact computation instead.
+    fn orientation([ax, ay]: [Self; 2], [bx, by]: [Self; 2], [cx, cy]: [Self; 2]) -> Ordering {
+        (ax * by + bx * cy + cx * ay)
+            .partial_cmp(&(ax * cy + bx * ay + cx * by))
+            .unwrap_or(Ordering::Equal)
+    }
 }
 
 macro_rules! scalar_unsigned_impl {
@@ -77,6 +91,9 @@ macro_rules! scalar_float_impl {
             fn abs(self) -> Self {
                 self.abs()
             }
+            fn orientation(a: [Self; 2], b: [Self; 2], c: [Self; 2]) -> Ordering {
+                crate::hull::robust_orientation(a, b, c)
+            }
         }
     };
 }
@@ -122,6 +139,129 @@ pub trait FloatingScalar: Scalar + Mul<Output = Self> + Neg<Output = Self> {
     }
     /// Get the four-quadrant arctangent
     fn atan2(self, other: Self) -> Self;
+    /// Get the arccosine
+    fn acos(self) -> Self {
+        ((Self::ONE - self) * (Self::ONE + self)).sqrt().atan2(self)
+    }
+    /// Get the value of positive infinity
+    fn infinity() -> Self {
+        Self::ONE / Self::ZERO
+    }
+    /// Convert a `usize` to the nearest scalar value
+    fn from_usize(n: usize) -> Self {
+        (0..std::mem::size_of::<usize>() * 8)
+            .rev()
+            .fold(Self::ZERO, |sum, bit| {
+                let sum = sum * Self::TWO;
+                if n >> bit & 1 == 1 {
+                    sum + Self::ONE
+                } else {
+                    sum
+                }
+            })
+    }
+    /// Convert to a `usize`, rounding toward zero and saturating at the bounds of `usize`
+    fn to_usize(self) -> usize {
+        let bits = std::mem::size_of::<usize>() * 8;
+        let mut whole = truncate(self);
+        if matches!(whole.partial_cmp(&Self::ONE), None | Some(Ordering::Less)) {
+            return 0;
+        }
+        let mut power = (1..bits).fold(Self::ONE, |power, _| power * Self::TWO);
+        if whole >= power * Self::TWO {
+            return usize::MAX;
+        }
+        let mut n = 0;
+        for bit in (0..bits).rev() {
+            if whole >= power {
+                whole = whole - power;
+                n |= 1 << bit;
+            }
+            power = power / Self::TWO;
+        }
+        n
+    }
+    /// Get the largest integer less than or equal to the scalar
+    fn floor(self) -> Self {
+        let whole = truncate(self);
+        if whole > self {
+            whole - Self::ONE
+        } else {
+            whole
+        }
+    }
+    /// Get the smallest integer greater than or equal to the scalar
+    fn ceil(self) -> Self {
+        -(-self).floor()
+    }
+    /// Get the nearest integer to the scalar, rounding half-way cases away from zero
+    fn round(self) -> Self {
+        let whole = truncate(self.abs());
+        let rounded = if self.abs() - whole >= Self::ONE / Self::TWO {
+            whole + Self::ONE
+        } else {
+            whole
+        };
+        if self < Self::ZERO {
+            -rounded
+        } else {
+            rounded
+        }
+    }
+    /// Get the natural logarithm
+    fn ln(self) -> Self {
+        let infinity = Self::infinity();
+        if self == Self::ZERO {
+            return -infinity;
+        }
+        if self.partial_cmp(&Self::ZERO) != Some(Ordering::Greater) {
+            return Self::ZERO * infinity;
+        }
+        if self == infinity {
+            return self;
+        }
+        // Scale by powers of two to near 1, where the series converges quickly
+        let (low, high) = (
+            Self::TWO / (Self::TWO + Self::ONE),
+            Self::TWO.square() / (Self::TWO + Self::ONE),
+        );
+        let (mut mantissa, mut exponent) = (self, Self::ZERO);
+        while mantissa > high {
+            mantissa = mantissa / Self::TWO;
+            exponent = exponent + Self::ONE;
+        }
+        while mantissa < low {
+            mantissa = mantissa * Self::TWO;
+            exponent = exponent - Self::ONE;
+        }
+        let ln2 = atanh_series(Self::ONE / (Self::TWO + Self::ONE)) * Self::TWO;
+        exponent * ln2 + atanh_series((mantissa - Self::ONE) / (mantissa + Self::ONE)) * Self::TWO
+    }
+    /// Get e raised to the power of the scalar
+    ///
+    /// The default implementation's relative error grows with the magnitude of the scalar.
+    fn exp(self) -> Self {
+        // NaN and infinity are returned as is
+        if self.partial_cmp(&self).is_none() || self == Self::infinity() {
+            return self;
+        }
+        if self < Self::ZERO {
+            return Self::ONE / (-self).exp();
+        }
+        // e^x is e^n e^f for the integer and fractional parts of x
+        let whole = truncate(self);
+        let (mut base, mut power, mut result) =
+            (exp_series(Self::ONE), whole, exp_series(self - whole));
+        while power >= Self::ONE && result < Self::infinity() {
+            let half = truncate(power / Self::TWO);
+            if power - half * Self::TWO >= Self::ONE {
+                result = result * base;
+            }
+            base = base * base;
+            power = half;
+        }
+        result
+    }
     /// Linear interpolate the scalar with another
     fn lerp(self, other: Self, t: Self) -> Self {
         (Self::ONE - t) * self + t * other
@@ -140,12 +280,75 @@ pub trait FloatingScalar: Scalar + Mul<Output = Self> + Neg<Output = Self> {
     }
 }
 
+/// Round a scalar toward zero by summing the powers of two in its integer part
+fn truncate<S>(s: S) -> S
+where
+    S: FloatingScalar,
+{
+    let mag = s.abs();
+    // Infinities and NaN are returned as is
+    if mag.partial_cmp(&S::infinity()) != Some(Ordering::Less) {
+        return s;
+    }
+    let mut power = S::ONE;
+    while power * S::TWO <= mag {
+        power = power * S::TWO;
+    }
+    let mut whole = S::ZERO;
+    while power >= S::ONE {
+        if whole + power <= mag {
+            whole = whole + power;
+        }
+        power = power / S::TWO;
+    }
+    if s < S::ZERO {
+        -whole
+    } else {
+        whole
+    }
+}
+
+/// Sum the Taylor series of e^x, which converges quickly for small x
+fn exp_series<S>(x: S) -> S
+where
+    S: FloatingScalar,
+{
+    let (mut sum, mut term, mut n) = (S::ONE, S::ONE, S::ONE);
+    while term > sum * S::EPSILON {
+        term = term * x / n;
+        sum = sum + term;
+        n = n + S::ONE;
+    }
+    sum
+}
+
+/// Sum the Taylor series of the inverse hyperbolic tangent,
+/// which converges quickly for small x
+fn atanh_series<S>(x: S) -> S
+where
+    S: FloatingScalar,
+{
+    let (mut sum, mut power, mut n) = (x, x, S::ONE);
+    loop {
+        power = power * x * x;
+        n = n + S::TWO;
+        let term = power / n;
+        if term.abs() <= sum.abs() * S::EPSILON {
+            return sum;
+        }
+        sum = sum + term;
+    }
+}
+
 macro_rules! floating_scalar_impl {
     ($type:ty, $pi:expr, $epsilon:expr) => {
         impl FloatingScalar for $type {
             const PI: Self = $pi;
             const TAU: Self = $pi * 2.0;
             const EPSILON: Self = $epsilon;
+            fn infinity() -> Self {
+                Self::INFINITY
+            }
             fn sqrt(self) -> Self {
                 Self::sqrt(self)
             }
@@ -158,9 +361,167 @@ macro_rules! floating_scalar_impl {
             fn atan2(self, other: Self) -> Self {
                 self.atan2(other)
             }
+            fn acos(self) -> Self {
+                Self::acos(self)
+            }
+            fn from_usize(n: usize) -> Self {
+                n as Self
+            }
+            fn to_usize(self) -> usize {
+                self as usize
+            }
+            fn floor(self) -> Self {
+                Self::floor(self)
+            }
+            fn ceil(self) -> Self {
+                Self::ceil(self)
+            }
+            fn round(self) -> Self {
+                Self::round(self)
+            }
+            fn ln(self) -> Self {
+                Self::ln(self)
+            }
+            fn exp(self) -> Self {
+                Self::exp(self)
+            }
         }
     };
 }
 
-floating_scalar_impl!(f32, std::f32::consts::PI, std::f32::EPSILON);
-floating_scalar_impl!(f64, std::f64::consts::PI, std::f64::EPSILON);
+floating_scalar_impl!(f32, std::f32::consts::PI, f32::EPSILON);
+floating_scalar_impl!(f64, std::f64::consts::PI, f64::EPSILON);
+
+#[cfg(test)]
+#[test]
+fn floating_defaults() {
+    use std::ops::{Add, Div, Mul, Neg, Sub};
+    // A float that only implements the required methods
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    struct F(f64);
+    impl Add for F {
+        type Output = F;
+        fn add(self, other: F) -> F {
+            F(self.0 + other.0)
+        }
+    }
+    impl Sub for F {
+        type Output = F;
+        fn sub(self, other: F) -> F {
+            F(self.0 - other.0)
+        }
+    }
+    impl Mul for F {
+        type Output = F;
+        fn mul(self, other: F) -> F {
+            F(self.0 * other.0)
+        }
+    }
+    impl Div for F {
+        type Output = F;
+        fn div(self, other: F) -> F {
+            F(self.0 / other.0)
+        }
+    }
+    impl Neg for F {
+        type Output = F;
+        fn neg(self) -> F {
+            F(-self.0)
+        }
+    }
+    impl Scalar for F {
+        const ZERO: F = F(0.0);
+        const ONE: F = F(1.0);
+        const TWO: F = F(2.0);
+        fn abs(self) -> F {
+            F(self.0.abs())
+        }
+    }
+    impl FloatingScalar for F {
+        const TAU: F = F(std::f64::consts::PI * 2.0);
+        const PI: F = F(std::f64::consts::PI);
+        const EPSILON: F = F(f64::EPSILON);
+        fn sqrt(self) -> F {
+            F(self.0.sqrt())
+        }
+        fn cos(self) -> F {
+            F(self.0.cos())
+        }
+        fn sin(self) -> F {
+            F(self.0.sin())
+        }
+        fn atan2(self, other: F) -> F {
+            F(self.0.atan2(other.0))
+        }
+    }
+    for &x in &[
+        0.0,
+        -0.0,
+        0.5,
+        -0.5,
+        1.0,
+        2.5,
+        -2.5,
+        3.7,
+        -3.7,
+        0.49999999999999994,
+        1e300,
+        -1e17,
+        4503599627370497.0,
+    ] {
+        assert_eq!(x.floor(), F(x).floor().0, "{}", x);
+        assert_eq!(x.ceil(), F(x).ceil().0, "{}", x);
+        assert_eq!(x.round(), F(x).round().0, "{}", x);
+    }
+    assert!(F(f64::NAN).floor().0.is_nan());
+    for &x in &[
+        1e-300,
+        0.1,
+        0.5,
+        1.0,
+        2.0,
+        std::f64::consts::E,
+        10.0,
+        12345.678,
+        1e300,
+    ] {
+        assert!(
+            (x.ln() - F(x).ln().0).abs() <= 1e-14 * x.ln().abs().max(1.0),
+            "{}",
+            x
+        );
+    }
+    for &x in &[-700.0, -20.0, -1.0, 0.0, 1e-10, 0.5, 1.0, 2.5, 30.0, 700.0] {
+        let error = ((x.exp() - F(x).exp().0) / x.exp()).abs();
+        assert!(error <= 1e-15 * (1.0 + x.abs()), "{}", x);
+    }
+    assert_eq!(f64::NEG_INFINITY, F(0.0).ln().0);
+    assert!(F(-1.0).ln().0.is_nan());
+    assert_eq!(f64::INFINITY, F(1000.0).exp().0);
+    assert_eq!(0.0, F(-1000.0).exp().0);
+    for &x in &[-1.0, -0.75, -1e-9, 0.0, 0.3, 0.999999, 1.0] {
+        assert!((x.acos() - F(x).acos().0).abs() < 1e-15, "{}", x);
+    }
+    assert!(F(1.5).acos().0.is_nan());
+    assert_eq!(f64::INFINITY, F::infinity().0);
+    for &n in &[0, 1, 2, 7, 1000, 123_456_789, usize::MAX] {
+        assert_eq!(n as f64, F::from_usize(n).0);
+    }
+    for &x in &[
+        -5.0,
+        -0.5,
+        0.0,
+        0.99,
+        1.0,
+        7.5,
+        123456789.9,
+        1e30,
+        f64::INFINITY,
+        f64::NAN,
+    ] {
+        assert_eq!(x as usize, F(x).to_usize(), "{}", x);
+    }
+    assert_eq!(0.0, F(f64::NEG_INFINITY).exp().0);
+    assert_eq!(f64::INFINITY, F(f64::INFINITY).exp().0);
+    assert_eq!(f64::NEG_INFINITY, F(f64::NEG_INFINITY).round().0);
+}
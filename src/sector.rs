@@ -0,0 +1,208 @@
+//! Module for the [`Sector`] trait
+
+use crate::{Arc, FloatingScalar, FloatingVector2, Rectangle, Scalar as _, Vector2};
+
+/// The scalar type of a [`Sector`]
+pub type Scalar<T> = <<T as Sector>::Vector as Vector2>::Scalar;
+
+/**
+Trait for manipulating circular sectors
+
+A sector is the region between two radii of a circle and the [`Arc`] between them.
+A sector may also have an inner radius, in which case it is the part of an annulus
+between the two radii. This is useful for things like gauges and donut charts.
+
+It is implemented for any pair of an [`Arc`] and its scalar type,
+where the scalar is the inner radius.
+```
+use vector2math::*;
+
+let slice = f64::Sect::new([0.0, 0.0], 2.0, 0.0, f64::PI / 2.0);
+assert!(slice.contains([1.0, 1.0]));
+assert!(!slice.contains([-1.0, 1.0]));
+assert_eq!(f64::PI, slice.area());
+
+let gauge = f64::Sect::new_annular([0.0, 0.0], 1.0, 2.0, 0.0, f64::PI);
+assert!(!gauge.contains([0.0, 0.5]));
+assert!(gauge.contains([0.0, 1.5]));
+```
+*/
+pub trait Sector: Copy
+where
+    Scalar<Self>: FloatingScalar,
+{
+    /// The arc type
+    type Arc: Arc<Vector = Self::Vector>;
+    /// The vector type
+    type Vector: FloatingVector2;
+    /// Create a new sector from its outer arc and an inner radius
+    fn from_arc(arc: Self::Arc, inner_radius: Scalar<Self>) -> Self;
+    /// Get the sector's outer arc
+    fn arc(self) -> Self::Arc;
+    /// Get the sector's inner radius
+    fn inner_radius(self) -> Scalar<Self>;
+    /// Create a new sector with no inner radius from a center, a radius, a start angle, and a sweep
+    fn new(
+        center: Self::Vector,
+        radius: Scalar<Self>,
+        start: Scalar<Self>,
+        sweep: Scalar<Self>,
+    ) -> Self {
+        Self::from_arc(
+            Self::Arc::new(center, radius, start, sweep),
+            Scalar::<Self>::ZERO,
+        )
+    }
+    /// Create a new sector from a center, inner and outer radii, a start angle, and a sweep
+    fn new_annular(
+        center: Self::Vector,
+        inner_radius: Scalar<Self>,
+        outer_radius: Scalar<Self>,
+        start: Scalar<Self>,
+        sweep: Scalar<Self>,
+    ) -> Self {
+        Self::from_arc(
+            Self::Arc::new(center, outer_radius, start, sweep),
+            inner_radius,
+        )
+    }
+    /// Get the center
+    fn center(self) -> Self::Vector {
+        self.arc().center()
+    }
+    /// Get the outer radius
+    fn outer_radius(self) -> Scalar<Self> {
+        self.arc().radius()
+    }
+    /// Get the sector's inner arc
+    ///
+    /// If the inner radius is zero, the arc has no length.
+    fn inner_arc(self) -> Self::Arc {
+        let arc = self.arc();
+        Self::Arc::new(
+            arc.center(),
+            self.inner_radius(),
+            arc.start_angle(),
+            arc.sweep(),
+        )
+    }
+    /// Transform the sector into one with a different outer arc
+    fn with_arc(self, arc: Self::Arc) -> Self {
+        Self::from_arc(arc, self.inner_radius())
+    }
+    /// Transform the sector into one with a different inner radius
+    fn with_inner_radius(self, inner_radius: Scalar<Self>) -> Self {
+        Self::from_arc(self.arc(), inner_radius)
+    }
+    /// Get the sector that is this one translated by some vector
+    fn translated(self, offset: Self::Vector) -> Self {
+        self.with_arc(self.arc().translated(offset))
+    }
+    /// Get the absolute sweep in radians, capped at a full turn
+    fn abs_sweep(self) -> Scalar<Self> {
+        self.arc().sweep().abs().minn(Scalar::<Self>::TAU)
+    }
+    /// Get the area
+    fn area(self) -> Scalar<Self> {
+        (self.outer_radius().square() - self.inner_radius().square()).abs() * self.abs_sweep()
+            / Scalar::<Self>::TWO
+    }
+    /// Get the perimeter
+    fn perimeter(self) -> Scalar<Self> {
+        let (outer, inner) = (self.outer_radius().abs(), self.inner_radius().abs());
+        let arcs = (outer + inner) * self.abs_sweep();
+        if self.abs_sweep() >= Scalar::<Self>::TAU {
+            arcs
+        } else {
+            arcs + (outer - inner).abs() * Scalar::<Self>::TWO
+        }
+    }
+    /// Check that the sector contains the given point. Includes edges.
+    fn contains(self, point: Self::Vector) -> bool {
+        let offset = point.sub(self.center());
+        let dist = offset.mag();
+        let (outer, inner) = (self.outer_radius().abs(), self.inner_radius().abs());
+        if dist > outer.maxx(inner) || dist < outer.minn(inner) {
+            return false;
+        }
+        dist.is_zero() || self.arc().contains_angle(offset.atan())
+    }
+    /// Check that the sector contains all points
+    fn contains_all<I>(self, points: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        points.into_iter().all(|point| self.contains(point))
+    }
+    /// Check that the sector contains any point
+    fn contains_any<I>(self, points: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        points.into_iter().any(|point| self.contains(point))
+    }
+    /// Get the smallest axis-aligned rectangle that contains the sector
+    fn bounding_rect<R>(self) -> R
+    where
+        R: Rectangle<Vector = Self::Vector>,
+    {
+        let outer: R = self.arc().bounding_rect();
+        let inner: R = self.inner_arc().bounding_rect();
+        let min = outer.top_left().min2(inner.top_left());
+        let max = outer.bottom_right().max2(inner.bottom_right());
+        R::new(min, max.sub(min))
+    }
+    /// Get points along the sector's boundary
+    ///
+    /// The points go along the outer arc and then back along the inner arc,
+    /// or to the center if there is no inner radius. They are spaced so that
+    /// the polygon they form deviates from the sector by no more than `tolerance`.
+    fn outline(self, tolerance: Scalar<Self>) -> Vec<Self::Vector> {
+        let mut points = self.arc().flatten(tolerance);
+        if self.inner_radius().is_zero() {
+            points.push(self.center());
+        } else {
+            points.extend(self.inner_arc().reversed().flatten(tolerance));
+        }
+        points
+    }
+}
+
+impl<A, S> Sector for (A, S)
+where
+    A: Arc,
+    A::Vector: FloatingVector2<Scalar = S>,
+    S: FloatingScalar,
+{
+    type Arc = A;
+    type Vector = A::Vector;
+    fn from_arc(arc: Self::Arc, inner_radius: Scalar<Self>) -> Self {
+        (arc, inner_radius)
+    }
+    fn arc(self) -> Self::Arc {
+        self.0
+    }
+    fn inner_radius(self) -> Scalar<Self> {
+        self.1
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn sector() {
+    use crate::f64;
+    let gauge = f64::Sect::new_annular([0.0, 0.0], 1.0, 2.0, -f64::PI / 4.0, f64::PI);
+    let bounds: f64::Rect = gauge.bounding_rect();
+    let s = 2f64.sqrt() / 2.0;
+    assert!(bounds.top_left().dist([-2.0 * s, -2.0 * s]) < 1e-12);
+    assert!(bounds.bottom_right().dist([2.0, 2.0]) < 1e-12);
+    assert!((gauge.area() - 1.5 * f64::PI).abs() < 1e-12);
+    assert!((gauge.perimeter() - (3.0 * f64::PI + 2.0)).abs() < 1e-12);
+    assert!(gauge.contains([1.5, 0.0]));
+    assert!(!gauge.contains([0.0, -1.5]));
+    let outline = gauge.outline(0.01);
+    assert!(outline.iter().all(|&p| {
+        let dist = p.mag();
+        (dist - 1.0).abs() < 1e-12 || (dist - 2.0).abs() < 1e-12
+    }));
+}
@@ -37,6 +37,26 @@ macro_rules! float_mod {
             pub type Rect = [super::$V; 2];
             /// A standard circle type
             pub type Circ = (super::$V, $T);
+            /// A standard ellipse type
+            pub type Ellip = (super::$V, super::$V, $T);
+            /// A standard oriented rectangle type
+            pub type ORect = (Rect, $T);
+            /// A standard rounded rectangle type
+            pub type RRect = (Rect, [$T; 4]);
+            /// A standard capsule type
+            pub type Caps = (Circ, super::$V);
+            /// A standard arc type
+            pub type Arc = (Circ, $T, $T);
+            /// A standard sector type
+            pub type Sect = (Arc, $T);
+            /// A standard annulus type
+            pub type Annul = (Circ, $T);
+            /// A standard triangle type
+            pub type Tri = [super::$V; 3];
+            /// A standard quadratic Bézier curve type
+            pub type QuadBez = [super::$V; 3];
+            /// A standard cubic Bézier curve type
+            pub type CubicBez = [super::$V; 4];
             /// A standard transform type
             pub type Trans = [$T; 6];
         }
@@ -46,8 +66,16 @@ macro_rules! float_mod {
 float_mod!(f32, f32x2);
 float_mod!(f64, f64x2);
 
+// Methods that are not overridden here use the scalar defaults:
+// * `cross`, `perp`, `perp_cw`, `reflect` and `signum` have no matching lane-wise operation
+// * `abs` has no integer vector operation
+// * `sum` and `product` would wrap on overflow for integer vectors
+// * `FloatingVector2` is implemented for all vectors, so none of its methods can be overridden
 macro_rules! impl_simd_vector2 {
     ($Vector:ty, $Scalar:ty) => {
+        impl_simd_vector2!($Vector, $Scalar, {});
+    };
+    ($Vector:ty, $Scalar:ty, { $($float:tt)* }) => {
         impl Vector2 for $Vector {
             type Scalar = $Scalar;
             fn new(x: Self::Scalar, y: Self::Scalar) -> Self {
@@ -92,6 +120,16 @@ macro_rules! impl_simd_vector2 {
             fn div2_assign(&mut self, other: Self) {
                 *self /= other;
             }
+            fn min2(self, other: Self) -> Self {
+                self.min(other)
+            }
+            fn max2(self, other: Self) -> Self {
+                self.max(other)
+            }
+            fn clamp2(self, min: Self, max: Self) -> Self {
+                self.max(min).min(max)
+            }
+            $($float)*
         }
     };
 }
@@ -106,8 +144,24 @@ impl_simd_vector2!(i16x2, i16);
 impl_simd_vector2!(i32x2, i32);
 impl_simd_vector2!(i64x2, i64);
 
-impl_simd_vector2!(f32x2, f32);
-impl_simd_vector2!(f64x2, f64);
+macro_rules! impl_simd_floating_vector2 {
+    ($Vector:ty, $Scalar:ty) => {
+        impl_simd_vector2!($Vector, $Scalar, {
+            fn abs(self) -> Self {
+                self.abs()
+            }
+            fn sum(self) -> Self::Scalar {
+                self.sum()
+            }
+            fn product(self) -> Self::Scalar {
+                self.product()
+            }
+        });
+    };
+}
+
+impl_simd_floating_vector2!(f32x2, f32);
+impl_simd_floating_vector2!(f64x2, f64);
 
 #[cfg(test)]
 #[test]
@@ -0,0 +1,350 @@
+//! Module for simplifying polylines
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+};
+
+use crate::{
+    capsule::closest_on_segment, hull::orientation, FloatingScalar, FloatingVector2, Scalar as _,
+};
+
+/**
+A way of removing points from a polyline while keeping its shape
+
+The first and last points of a polyline are always kept.
+```
+use vector2math::*;
+
+let trace = [[0.0, 0.0], [1.0, 0.1], [2.0, -0.1], [3.0, 5.0], [4.0, 6.0], [5.0, 7.0]];
+assert_eq!(vec![0, 2, 3, 5], Simplification::DouglasPeucker(0.5).indices(&trace));
+assert_eq!(
+    vec![[0.0, 0.0], [2.0, -0.1], [3.0, 5.0], [5.0, 7.0]],
+    Simplification::Visvalingam(0.5).simplify(&trace)
+);
+assert_eq!(vec![0, 2, 3, 5], Simplification::Radial(1.5).indices(&trace));
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Simplification<S> {
+    /// Remove points that are closer than a distance to the last kept point
+    Radial(S),
+    /// Use the Ramer–Douglas–Peucker algorithm
+    ///
+    /// No removed point is further than the given distance from the simplified polyline.
+    DouglasPeucker(S),
+    /// Use the Visvalingam–Whyatt algorithm
+    ///
+    /// Points are removed in order of the area of the triangle they form with
+    /// their neighbors until every remaining triangle has at least the given area.
+    Visvalingam(S),
+}
+
+impl<S> Simplification<S>
+where
+    S: FloatingScalar,
+{
+    /// Get the indices of the points of a polyline that are kept, in order
+    pub fn indices<V>(self, points: &[V]) -> Vec<usize>
+    where
+        V: FloatingVector2<Scalar = S>,
+    {
+        if points.len() < 3 {
+            return (0..points.len()).collect();
+        }
+        match self {
+            Simplification::Radial(distance) => radial(points, distance),
+            Simplification::DouglasPeucker(distance) => douglas_peucker(points, distance),
+            Simplification::Visvalingam(area) => visvalingam(points, area),
+        }
+    }
+    /// Get the points of a polyline that are kept
+    pub fn simplify<V>(self, points: &[V]) -> Vec<V>
+    where
+        V: FloatingVector2<Scalar = S>,
+    {
+        self.indices(points)
+            .into_iter()
+            .map(|i| points[i])
+            .collect()
+    }
+    /// Get the indices of the points of a polyline that are kept, without
+    /// making the simplified polyline cross itself
+    ///
+    /// Where simplified segments would touch or fold back on each other, removed
+    /// points are restored until they do not. Places where the original polyline
+    /// touches itself are kept.
+    pub fn indices_preserving_topology<V>(self, points: &[V]) -> Vec<usize>
+    where
+        V: FloatingVector2<Scalar = S>,
+    {
+        let mut kept = self.indices(points);
+        loop {
+            let mut restore = Vec::new();
+            for i in 0..kept.len().saturating_sub(1) {
+                for j in i + 1..kept.len() - 1 {
+                    let (a, b) = (points[kept[i]], points[kept[i + 1]]);
+                    let (c, d) = (points[kept[j]], points[kept[j + 1]]);
+                    // Neighboring segments share a point, so they only overlap if they fold back
+                    let touch = if j == i + 1 {
+                        folds_back(a, b, d)
+                    } else {
+                        segments_touch(a, b, c, d)
+                    };
+                    if touch {
+                        restore.extend(furthest_between(points, kept[i], kept[i + 1]));
+                        restore.extend(furthest_between(points, kept[j], kept[j + 1]));
+                    }
+                }
+            }
+            if restore.is_empty() {
+                return kept;
+            }
+            kept.extend(restore);
+            kept.sort_unstable();
+            kept.dedup();
+        }
+    }
+    /// Get the points of a polyline that are kept, without making
+    /// the simplified polyline cross itself
+    ///
+    /// See [`Simplification::indices_preserving_topology`].
+    pub fn simplify_preserving_topology<V>(self, points: &[V]) -> Vec<V>
+    where
+        V: FloatingVector2<Scalar = S>,
+    {
+        self.indices_preserving_topology(points)
+            .into_iter()
+            .map(|i| points[i])
+            .collect()
+    }
+}
+
+fn radial<V>(points: &[V], distance: V::Scalar) -> Vec<usize>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let last = points.len() - 1;
+    let mut kept = vec![0];
+    for i in 1..last {
+        if points[i].dist(points[kept[kept.len() - 1]]) >= distance {
+            kept.push(i);
+        }
+    }
+    kept.push(last);
+    kept
+}
+
+fn douglas_peucker<V>(points: &[V], distance: V::Scalar) -> Vec<usize>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+    let mut spans = vec![(0, points.len() - 1)];
+    while let Some((start, end)) = spans.pop() {
+        if let Some((i, dist)) = furthest(points, start, end) {
+            if dist > distance {
+                keep[i] = true;
+                spans.push((start, i));
+                spans.push((i, end));
+            }
+        }
+    }
+    (0..points.len()).filter(|&i| keep[i]).collect()
+}
+
+/// A point's effective area in the Visvalingam–Whyatt algorithm
+struct Effective<S> {
+    area: S,
+    index: usize,
+}
+
+impl<S: PartialOrd> PartialEq for Effective<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S: PartialOrd> Eq for Effective<S> {}
+
+impl<S: PartialOrd> PartialOrd for Effective<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: PartialOrd> Ord for Effective<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.area
+            .partial_cmp(&other.area)
+            .unwrap_or(Ordering::Equal)
+            .then(self.index.cmp(&other.index))
+    }
+}
+
+fn visvalingam<V>(points: &[V], area: V::Scalar) -> Vec<usize>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let n = points.len();
+    let mut prev: Vec<usize> = (0..n).map(|i| i.saturating_sub(1)).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1).min(n - 1)).collect();
+    let triangle = |a: usize, b: usize, c: usize| {
+        points[b]
+            .sub(points[a])
+            .cross(points[c].sub(points[a]))
+            .abs()
+            / V::Scalar::TWO
+    };
+    let mut areas: Vec<V::Scalar> = (0..n)
+        .map(|i| {
+            if i == 0 || i == n - 1 {
+                V::Scalar::infinity()
+            } else {
+                triangle(i - 1, i, i + 1)
+            }
+        })
+        .collect();
+    let mut heap: BinaryHeap<_> = (1..n - 1)
+        .map(|index| {
+            Reverse(Effective {
+                area: areas[index],
+                index,
+            })
+        })
+        .collect();
+    let mut removed = vec![false; n];
+    while let Some(Reverse(Effective { area: least, index })) = heap.pop() {
+        // Skip entries whose area has changed since they were added
+        if removed[index] || least != areas[index] {
+            continue;
+        }
+        if least >= area {
+            break;
+        }
+        removed[index] = true;
+        let (before, after) = (prev[index], next[index]);
+        next[before] = after;
+        prev[after] = before;
+        for &i in &[before, after] {
+            if i != 0 && i != n - 1 {
+                // A point's area never drops below that of a point removed before it,
+                // so that points are removed in order of significance
+                areas[i] = triangle(prev[i], i, next[i]).maxx(least);
+                heap.push(Reverse(Effective {
+                    area: areas[i],
+                    index: i,
+                }));
+            }
+        }
+    }
+    (0..n).filter(|&i| !removed[i]).collect()
+}
+
+/// Get the point between two indices that is furthest from the segment between them
+fn furthest<V>(points: &[V], start: usize, end: usize) -> Option<(usize, V::Scalar)>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let (a, b) = (points[start], points[end]);
+    (start + 1..end)
+        .map(|i| (i, points[i].dist(closest_on_segment(points[i], a, b))))
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+}
+
+/// Get the index of the point between two indices that is furthest from the segment between them
+fn furthest_between<V>(points: &[V], start: usize, end: usize) -> Option<usize>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    furthest(points, start, end).map(|(i, _)| i)
+}
+
+/// Check if two segments cross or touch
+fn segments_touch<V>(a: V, b: V, c: V, d: V) -> bool
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let (abc, abd) = (orientation(a, b, c), orientation(a, b, d));
+    let (cda, cdb) = (orientation(c, d, a), orientation(c, d, b));
+    let crosses = |p: Ordering, q: Ordering| p != Ordering::Equal && q != Ordering::Equal && p != q;
+    if crosses(abc, abd) && crosses(cda, cdb) {
+        return true;
+    }
+    // Collinear or touching at an endpoint
+    let on = |p: V, s: V, t: V| {
+        orientation(s, t, p) == Ordering::Equal && p.sub(s).dot(p.sub(t)) <= V::Scalar::ZERO
+    };
+    on(c, a, b) || on(d, a, b) || on(a, c, d) || on(b, c, d)
+}
+
+/// Check if the segment from `b` to `c` goes back along the segment from `a` to `b`
+fn folds_back<V>(a: V, b: V, c: V) -> bool
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    orientation(a, b, c) == Ordering::Equal && a.sub(b).dot(c.sub(b)) > V::Scalar::ZERO
+}
+
+#[cfg(test)]
+#[test]
+fn simplification() {
+    let zigzag: Vec<[f64; 2]> = (0..=20)
+        .map(|i| [i as f64, if i % 2 == 0 { 0.0 } else { 0.1 }])
+        .collect();
+    for &method in &[
+        Simplification::Radial(5.0),
+        Simplification::DouglasPeucker(0.5),
+        Simplification::Visvalingam(1.0),
+    ] {
+        let kept = method.indices(&zigzag);
+        assert_eq!(Some(&0), kept.first());
+        assert_eq!(Some(&20), kept.last());
+        assert!(kept.len() < 6);
+    }
+    assert_eq!(
+        vec![0, 20],
+        Simplification::DouglasPeucker(0.5).indices(&zigzag)
+    );
+    assert_eq!(
+        vec![0, 5, 10, 15, 20],
+        Simplification::Radial(5.0).indices(&zigzag)
+    );
+    // Points within the distance are never removed
+    assert_eq!(
+        21,
+        Simplification::DouglasPeucker(0.05).indices(&zigzag).len()
+    );
+    // A dent that a later part of the polyline reaches into
+    let dented = [
+        [0.0, 0.0],
+        [5.0, -0.5],
+        [10.0, 0.0],
+        [10.0, 5.0],
+        [5.0, 5.0],
+        [5.0, -0.2],
+        [4.0, -0.2],
+    ];
+    let method = Simplification::DouglasPeucker(2.0);
+    assert_eq!(vec![0, 2, 3, 4, 6], method.indices(&dented));
+    let kept = method.simplify_preserving_topology(&dented);
+    for i in 0..kept.len() - 1 {
+        for j in i + 2..kept.len() - 1 {
+            assert!(!segments_touch(kept[i], kept[i + 1], kept[j], kept[j + 1]));
+        }
+    }
+    assert_eq!(dented.to_vec(), kept);
+    // A spike whose simplified tip would fold back onto its own line
+    let spike = [[0.0, 0.0], [5.0, 0.3], [10.0, 0.0], [4.0, 0.0]];
+    assert_eq!(vec![0, 2, 3], method.indices(&spike));
+    assert_eq!(vec![0, 1, 2, 3], method.indices_preserving_topology(&spike));
+}
@@ -0,0 +1,371 @@
+//! Module for the [`Spline`] trait and spline types
+
+use crate::{CubicBezier, FloatingScalar, FloatingVector2, Scalar as _, Vector2};
+
+/// The scalar type of a [`Spline`]
+pub type Scalar<T> = <<T as Spline>::Vector as Vector2>::Scalar;
+
+/**
+Trait for piecewise cubic curves
+
+A spline is made of a number of segments, each of which is a cubic
+Bézier curve. The spline is evaluated by a global parameter `t` that
+goes from 0 at the start of the first segment to 1 at the end of the last,
+with each segment taking up an equal share of the range.
+
+Methods that evaluate the spline panic if it has no segments.
+```
+use vector2math::*;
+
+let waypoints = [[0.0, 0.0], [1.0, 1.0], [2.0, 0.0], [3.0, 1.0]];
+let path = CatmullRom::uniform(&waypoints);
+assert_eq!(3, path.segment_count());
+assert_eq!([0.0, 0.0], path.eval(0.0));
+assert_eq!([2.0, 0.0], path.eval(2.0 / 3.0));
+assert_eq!([3.0, 1.0], path.eval(1.0));
+```
+*/
+pub trait Spline: Copy
+where
+    Scalar<Self>: FloatingScalar,
+{
+    /// The vector type
+    type Vector: FloatingVector2;
+    /// Get the number of segments
+    fn segment_count(self) -> usize;
+    /// Get the control points of the cubic Bézier curve of the segment at the given index
+    fn segment(self, index: usize) -> [Self::Vector; 4];
+    /// Get the index of the segment at the global parameter `t` and
+    /// the parameter within that segment
+    fn locate(self, t: Scalar<Self>) -> (usize, Scalar<Self>) {
+        let count = self.segment_count();
+        let scaled = t.maxx(Scalar::<Self>::ZERO) * Scalar::<Self>::from_usize(count);
+        let index = scaled.to_usize().min(count.saturating_sub(1));
+        (index, scaled - Scalar::<Self>::from_usize(index))
+    }
+    /// Get the point on the spline at the global parameter `t`
+    fn eval(self, t: Scalar<Self>) -> Self::Vector {
+        let (index, local) = self.locate(t);
+        self.segment(index).eval(local)
+    }
+    /// Get the derivative of the spline with respect to the global parameter at `t`
+    fn derivative(self, t: Scalar<Self>) -> Self::Vector {
+        let (index, local) = self.locate(t);
+        self.segment(index)
+            .derivative(local)
+            .mul(Scalar::<Self>::from_usize(self.segment_count()))
+    }
+    /// Get the second derivative of the spline with respect to the global parameter at `t`
+    fn second_derivative(self, t: Scalar<Self>) -> Self::Vector {
+        let (index, local) = self.locate(t);
+        self.segment(index)
+            .second_derivative(local)
+            .mul(Scalar::<Self>::from_usize(self.segment_count()).square())
+    }
+    /// Get the cubic Bézier curves that make up the spline
+    fn to_cubics<C>(self) -> Vec<C>
+    where
+        C: CubicBezier<Vector = Self::Vector>,
+    {
+        (0..self.segment_count())
+            .map(|i| C::from_control_points(self.segment(i)))
+            .collect()
+    }
+    /// Get an estimate of the spline's length
+    ///
+    /// Each segment is subdivided until the estimate of each piece is within `tolerance`.
+    fn arc_length(self, tolerance: Scalar<Self>) -> Scalar<Self> {
+        (0..self.segment_count()).fold(Scalar::<Self>::ZERO, |sum, i| {
+            sum + self.segment(i).arc_length(tolerance)
+        })
+    }
+    /// Get a table that maps distances along the spline to global parameters
+    ///
+    /// Each segment is sampled `samples_per_segment` times. More samples
+    /// give more accurate results.
+    fn arc_length_table(self, samples_per_segment: usize) -> ArcLengthTable<Scalar<Self>> {
+        let samples_per_segment = samples_per_segment.max(1);
+        let count = self.segment_count() * samples_per_segment;
+        let mut lengths = Vec::with_capacity(count + 1);
+        lengths.push(Scalar::<Self>::ZERO);
+        let mut total = Scalar::<Self>::ZERO;
+        if count > 0 {
+            let mut prev = self.eval(Scalar::<Self>::ZERO);
+            for i in 1..=count {
+                let point =
+                    self.eval(Scalar::<Self>::from_usize(i) / Scalar::<Self>::from_usize(count));
+                total = total + prev.dist(point);
+                lengths.push(total);
+                prev = point;
+            }
+        }
+        ArcLengthTable { lengths }
+    }
+    /// Get the point on the spline at some distance along it, using a table
+    /// created with [`Spline::arc_length_table`]
+    ///
+    /// Evaluating at evenly spaced distances gives constant-speed traversal.
+    fn eval_at_length(
+        self,
+        table: &ArcLengthTable<Scalar<Self>>,
+        distance: Scalar<Self>,
+    ) -> Self::Vector {
+        self.eval(table.parameter(distance))
+    }
+}
+
+/**
+A table that maps distances along a [`Spline`] to global parameters
+
+It is created with [`Spline::arc_length_table`].
+*/
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ArcLengthTable<S> {
+    lengths: Vec<S>,
+}
+
+impl<S> ArcLengthTable<S>
+where
+    S: FloatingScalar,
+{
+    /// Get the total length of the spline
+    pub fn length(&self) -> S {
+        self.lengths.last().copied().unwrap_or(S::ZERO)
+    }
+    /// Get the global parameter of the point at some distance along the spline
+    ///
+    /// The distance is clamped to the length of the spline.
+    pub fn parameter(&self, distance: S) -> S {
+        let intervals = self.lengths.len().saturating_sub(1);
+        if intervals == 0 {
+            return S::ZERO;
+        }
+        let index = self
+            .lengths
+            .partition_point(|&length| length < distance)
+            .max(1)
+            .min(intervals);
+        let (before, after) = (self.lengths[index - 1], self.lengths[index]);
+        let fraction = if after > before {
+            ((distance - before) / (after - before))
+                .maxx(S::ZERO)
+                .minn(S::ONE)
+        } else {
+            S::ZERO
+        };
+        (S::from_usize(index - 1) + fraction) / S::from_usize(intervals)
+    }
+}
+
+/**
+A Catmull-Rom spline that passes through a slice of points
+
+The `alpha` parameter controls the knot spacing. 0 gives a uniform spline,
+0.5 gives a centripetal spline, and 1 gives a chordal spline. Centripetal
+splines do not form cusps or self-intersections within a segment.
+
+The spline has a segment between each pair of adjacent points. The tangents
+at the first and last points are found by reflecting their neighbors.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CatmullRom<'a, V>
+where
+    V: Vector2,
+{
+    /// The points the spline passes through
+    pub points: &'a [V],
+    /// The knot spacing parameter
+    pub alpha: V::Scalar,
+}
+
+impl<'a, V> CatmullRom<'a, V>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    /// Create a new Catmull-Rom spline with the given knot spacing parameter
+    pub fn new(points: &'a [V], alpha: V::Scalar) -> Self {
+        CatmullRom { points, alpha }
+    }
+    /// Create a new uniform Catmull-Rom spline
+    pub fn uniform(points: &'a [V]) -> Self {
+        Self::new(points, V::Scalar::ZERO)
+    }
+    /// Create a new centripetal Catmull-Rom spline
+    pub fn centripetal(points: &'a [V]) -> Self {
+        Self::new(points, V::Scalar::ONE / V::Scalar::TWO)
+    }
+    fn knot_interval(self, a: V, b: V) -> V::Scalar {
+        let dist = a.dist(b);
+        if self.alpha.is_zero() || dist.is_zero() {
+            V::Scalar::ONE
+        } else {
+            (dist.ln() * self.alpha).exp()
+        }
+    }
+}
+
+impl<'a, V> Spline for CatmullRom<'a, V>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    type Vector = V;
+    fn segment_count(self) -> usize {
+        self.points.len().saturating_sub(1)
+    }
+    fn segment(self, index: usize) -> [Self::Vector; 4] {
+        let (p1, p2) = (self.points[index], self.points[index + 1]);
+        let p0 = if index == 0 {
+            p1.mul(V::Scalar::TWO).sub(p2)
+        } else {
+            self.points[index - 1]
+        };
+        let p3 = self
+            .points
+            .get(index + 2)
+            .copied()
+            .unwrap_or_else(|| p2.mul(V::Scalar::TWO).sub(p1));
+        let d0 = self.knot_interval(p0, p1);
+        let d1 = self.knot_interval(p1, p2);
+        let d2 = self.knot_interval(p2, p3);
+        let m1 = p1
+            .sub(p0)
+            .div(d0)
+            .sub(p2.sub(p0).div(d0 + d1))
+            .add(p2.sub(p1).div(d1))
+            .mul(d1);
+        let m2 = p2
+            .sub(p1)
+            .div(d1)
+            .sub(p3.sub(p1).div(d1 + d2))
+            .add(p3.sub(p2).div(d2))
+            .mul(d1);
+        let three = V::Scalar::TWO + V::Scalar::ONE;
+        [p1, p1.add(m1.div(three)), p2.sub(m2.div(three)), p2]
+    }
+}
+
+/**
+A uniform cubic B-spline defined by a slice of control points
+
+The spline does not generally pass through its control points.
+It has one fewer segment than it has control points beyond the third.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BSpline<'a, V> {
+    /// The control points
+    pub points: &'a [V],
+}
+
+impl<'a, V> BSpline<'a, V> {
+    /// Create a new B-spline
+    pub fn new(points: &'a [V]) -> Self {
+        BSpline { points }
+    }
+}
+
+impl<'a, V> Spline for BSpline<'a, V>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    type Vector = V;
+    fn segment_count(self) -> usize {
+        self.points.len().saturating_sub(3)
+    }
+    fn segment(self, index: usize) -> [Self::Vector; 4] {
+        let [p0, p1, p2, p3] = [
+            self.points[index],
+            self.points[index + 1],
+            self.points[index + 2],
+            self.points[index + 3],
+        ];
+        let three = V::Scalar::TWO + V::Scalar::ONE;
+        let four = V::Scalar::TWO + V::Scalar::TWO;
+        let six = three * V::Scalar::TWO;
+        [
+            p0.add(p1.mul(four)).add(p2).div(six),
+            p1.lerp(p2, V::Scalar::ONE / three),
+            p1.lerp(p2, V::Scalar::TWO / three),
+            p1.add(p2.mul(four)).add(p3).div(six),
+        ]
+    }
+}
+
+/**
+A cubic Hermite spline defined by slices of points and tangents
+
+The spline passes through each point with the corresponding tangent
+as its derivative with respect to the parameter of each segment.
+It has a segment between each pair of adjacent points. If there are
+fewer tangents than points, the extra points are ignored.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hermite<'a, V> {
+    /// The points the spline passes through
+    pub points: &'a [V],
+    /// The tangents at each point
+    pub tangents: &'a [V],
+}
+
+impl<'a, V> Hermite<'a, V> {
+    /// Create a new Hermite spline
+    pub fn new(points: &'a [V], tangents: &'a [V]) -> Self {
+        Hermite { points, tangents }
+    }
+}
+
+impl<'a, V> Spline for Hermite<'a, V>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    type Vector = V;
+    fn segment_count(self) -> usize {
+        self.points.len().min(self.tangents.len()).saturating_sub(1)
+    }
+    fn segment(self, index: usize) -> [Self::Vector; 4] {
+        let (p1, p2) = (self.points[index], self.points[index + 1]);
+        let (m1, m2) = (self.tangents[index], self.tangents[index + 1]);
+        let three = V::Scalar::TWO + V::Scalar::ONE;
+        [p1, p1.add(m1.div(three)), p2.sub(m2.div(three)), p2]
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn splines() {
+    let points = [[0.0, 0.0], [1.0, 0.0], [1.0, 5.0], [6.0, 5.0], [7.0, 0.0]];
+    for spline in &[
+        CatmullRom::uniform(&points),
+        CatmullRom::centripetal(&points),
+    ] {
+        for (i, &point) in points.iter().enumerate() {
+            assert!(spline.eval(i as f64 / 4.0).dist(point) < 1e-12);
+        }
+        let cubics: Vec<[[f64; 2]; 4]> = spline.to_cubics();
+        assert_eq!(cubics[1][3], cubics[2][0]);
+        let table = spline.arc_length_table(64);
+        assert!((table.length() - spline.arc_length(1e-6)).abs() < 1e-2);
+        let step = table.length() / 20.0;
+        let steps: Vec<f64> = (0..20)
+            .map(|i| {
+                let a = spline.eval_at_length(&table, step * i as f64);
+                a.dist(spline.eval_at_length(&table, step * (i + 1) as f64))
+            })
+            .collect();
+        assert!(steps.iter().all(|d| (d - step).abs() < step * 0.1));
+    }
+    let line = [[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [3.0, 3.0], [4.0, 4.0]];
+    let b = BSpline::new(&line);
+    assert_eq!(2, b.segment_count());
+    assert!(b.eval(0.5).dist([2.0, 2.0]) < 1e-12);
+    assert!(b.derivative(0.25).dist([2.0, 2.0]) < 1e-12);
+    let table = b.arc_length_table(16);
+    assert!((table.parameter(table.length() / 2.0) - 0.5).abs() < 1e-12);
+    let tangents = [[1.0, 0.0], [0.0, 1.0]];
+    let h = Hermite::new(&line[..2], &tangents);
+    assert!(h.derivative(0.0).dist([1.0, 0.0]) < 1e-12);
+    assert!(h.derivative(1.0).dist([0.0, 1.0]) < 1e-12);
+}
@@ -0,0 +1,399 @@
+//! Module for the [`Stroke`] type
+
+use crate::{circle::arc_points, FloatingScalar, FloatingVector2, Path};
+
+/// The shape used to join two segments of a stroke
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineJoin<S> {
+    /// Extend the outer edges of the segments until they meet
+    ///
+    /// If the ratio of the miter's length to the stroke width is greater than
+    /// the given limit, a bevel join is used instead.
+    Miter(S),
+    /// Round off the join with an arc
+    Round,
+    /// Cut off the join with a straight line
+    Bevel,
+    /// Cut off the join with a straight line half the stroke width from the pivot
+    Square,
+}
+
+/// The shape used at the ends of an open stroke
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LineCap {
+    /// End the stroke exactly at its end points
+    #[default]
+    Butt,
+    /// Extend the stroke with a half circle
+    Round,
+    /// Extend the stroke by half its width
+    Square,
+}
+
+/**
+A description of how to stroke lines and paths
+
+Stroking converts a polyline or [`Path`] into polygons that cover its outline.
+The polygons may overlap themselves and each other, so they should be
+filled with the [`FillRule::NonZero`](crate::FillRule::NonZero) fill rule.
+```
+use vector2math::*;
+
+let stroke = Stroke::new(2.0).with_cap(LineCap::Square);
+let outline = stroke.outline(&[[0.0, 0.0], [4.0, 0.0]], false, 0.1);
+assert_eq!(1, outline.len());
+let bounds: f64::Rect = f64::Rect::bounding(outline[0].iter().copied()).unwrap();
+assert_eq!([-1.0, -1.0, 6.0, 2.0], bounds);
+
+let dashed = stroke.with_dashes(vec![1.0, 1.0], 0.0);
+assert_eq!(2, dashed.outline(&[[0.0, 0.0], [4.0, 0.0]], false, 0.1).len());
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stroke<S> {
+    /// The width of the stroke
+    pub width: S,
+    /// The join between segments
+    pub join: LineJoin<S>,
+    /// The cap at the ends of open strokes and dashes
+    pub cap: LineCap,
+    /// The lengths of alternating dashes and gaps
+    ///
+    /// If this is empty, the stroke is solid.
+    pub dashes: Vec<S>,
+    /// The distance into the dash pattern at which the stroke starts
+    pub dash_offset: S,
+}
+
+impl<S> Stroke<S>
+where
+    S: FloatingScalar,
+{
+    /// Create a new solid stroke with the given width, miter joins with a limit of 4, and butt caps
+    pub fn new(width: S) -> Self {
+        Stroke {
+            width,
+            join: LineJoin::Miter(S::TWO + S::TWO),
+            cap: LineCap::Butt,
+            dashes: Vec::new(),
+            dash_offset: S::ZERO,
+        }
+    }
+    /// Transform the stroke into one with a different width
+    pub fn with_width(self, width: S) -> Self {
+        Stroke { width, ..self }
+    }
+    /// Transform the stroke into one with a different join
+    pub fn with_join(self, join: LineJoin<S>) -> Self {
+        Stroke { join, ..self }
+    }
+    /// Transform the stroke into one with a different cap
+    pub fn with_cap(self, cap: LineCap) -> Self {
+        Stroke { cap, ..self }
+    }
+    /// Transform the stroke into one with a dash pattern and offset
+    ///
+    /// If the pattern has an odd number of lengths, it is repeated to make it even.
+    pub fn with_dashes(self, mut dashes: Vec<S>, offset: S) -> Self {
+        if dashes.len() % 2 == 1 {
+            dashes.extend_from_within(..);
+        }
+        Stroke {
+            dashes,
+            dash_offset: offset,
+            ..self
+        }
+    }
+    /// Get the polygons that cover the stroked outline of a polyline
+    ///
+    /// If `closed` is true, the last point is connected back to the first.
+    /// Round joins and caps are approximated so that they deviate from
+    /// the true outline by no more than `tolerance`.
+    pub fn outline<V>(&self, points: &[V], closed: bool, tolerance: S) -> Vec<Vec<V>>
+    where
+        V: FloatingVector2<Scalar = S>,
+    {
+        let mut points = points.to_vec();
+        if closed && !points.is_empty() {
+            points.push(points[0]);
+        }
+        match self.dash(&points) {
+            Some(dashes) => dashes
+                .iter()
+                .flat_map(|dash| self.outline_open(dash, tolerance))
+                .collect(),
+            None if closed => self.outline_closed(&points, tolerance),
+            None => self.outline_open(&points, tolerance),
+        }
+    }
+    /// Get the polygons that cover the stroked outline of a path
+    ///
+    /// The path's curves are flattened so that they, along with
+    /// round joins and caps, deviate from the true outline by no
+    /// more than `tolerance`.
+    pub fn outline_path<V>(&self, path: &Path<V>, tolerance: S) -> Vec<Vec<V>>
+    where
+        V: FloatingVector2<Scalar = S>,
+    {
+        path.flatten(tolerance)
+            .into_iter()
+            .flat_map(|(points, closed)| self.outline(&points, closed, tolerance))
+            .collect()
+    }
+    /// Split a polyline into dashes, or return `None` if the stroke is solid
+    fn dash<V>(&self, points: &[V]) -> Option<Vec<Vec<V>>>
+    where
+        V: FloatingVector2<Scalar = S>,
+    {
+        let pattern = &self.dashes;
+        let total = pattern.iter().fold(S::ZERO, |sum, &len| sum + len.abs());
+        if pattern.is_empty() || total.is_zero() {
+            return None;
+        }
+        let mut offset = self.dash_offset - (self.dash_offset / total).floor() * total;
+        let mut index = 0;
+        while offset >= pattern[index].abs() {
+            offset = offset - pattern[index].abs();
+            index = (index + 1) % pattern.len();
+        }
+        let mut remaining = pattern[index].abs() - offset;
+        let mut dashes = Vec::new();
+        let mut current: Vec<V> = Vec::new();
+        if index % 2 == 0 {
+            current.extend(points.first().copied());
+        }
+        for pair in points.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            let length = a.dist(b);
+            let mut pos = S::ZERO;
+            while length - pos > remaining {
+                pos = pos + remaining;
+                current.push(a.lerp(b, pos / length));
+                if index % 2 == 0 {
+                    dashes.push(std::mem::take(&mut current));
+                }
+                index = (index + 1) % pattern.len();
+                remaining = pattern[index].abs();
+            }
+            remaining = remaining - (length - pos);
+            if index % 2 == 0 {
+                current.push(b);
+            }
+        }
+        if index % 2 == 0 && !current.is_empty() {
+            dashes.push(current);
+        }
+        Some(dashes)
+    }
+    /// Get the outline of an open polyline
+    fn outline_open<V>(&self, points: &[V], tolerance: S) -> Vec<Vec<V>>
+    where
+        V: FloatingVector2<Scalar = S>,
+    {
+        let mut points = points.to_vec();
+        points.dedup_by(|a, b| a.squared_dist(*b).is_zero());
+        let half_width = self.width.abs() / S::TWO;
+        if points.is_empty() || half_width.is_zero() {
+            return Vec::new();
+        }
+        if points.len() == 1 {
+            let center = points[0];
+            return match self.cap {
+                LineCap::Butt => Vec::new(),
+                LineCap::Round => vec![arc_points(center, half_width, S::ZERO, S::TAU, tolerance)],
+                LineCap::Square => {
+                    let offset = V::square(half_width);
+                    let corner = center.sub(offset);
+                    vec![vec![
+                        corner,
+                        V::new(corner.x(), center.y() + half_width),
+                        center.add(offset),
+                        V::new(center.x() + half_width, corner.y()),
+                    ]]
+                }
+            };
+        }
+        let mut outline = self.side(&points, false, tolerance);
+        points.reverse();
+        self.cap(&mut outline, points[1], points[0], tolerance);
+        outline.extend(self.side(&points, false, tolerance));
+        self.cap(
+            &mut outline,
+            points[points.len() - 2],
+            points[points.len() - 1],
+            tolerance,
+        );
+        vec![outline]
+    }
+    /// Get the outline of a closed polyline whose last point is the same as its first
+    fn outline_closed<V>(&self, points: &[V], tolerance: S) -> Vec<Vec<V>>
+    where
+        V: FloatingVector2<Scalar = S>,
+    {
+        let mut points = points.to_vec();
+        points.dedup_by(|a, b| a.squared_dist(*b).is_zero());
+        if points.len() < 3 || self.width.is_zero() {
+            return self.outline_open(&points, tolerance);
+        }
+        let outer = self.side(&points, true, tolerance);
+        points.reverse();
+        let inner = self.side(&points, true, tolerance);
+        vec![outer, inner]
+    }
+    /// Get the points along one side of a polyline with no repeated points
+    ///
+    /// The side is the one in the positive angle direction from the polyline's direction.
+    /// If `closed` is true, the last point must be the same as the first, and
+    /// a join is added there.
+    pub(crate) fn side<V>(&self, points: &[V], closed: bool, tolerance: S) -> Vec<V>
+    where
+        V: FloatingVector2<Scalar = S>,
+    {
+        let half_width = self.width.abs() / S::TWO;
+        let normal = |a: V, b: V| b.sub(a).unit().perp().mul(half_width);
+        let mut side = Vec::new();
+        let segments = points.len() - 1;
+        if !closed {
+            side.push(points[0].add(normal(points[0], points[1])));
+        }
+        let joins = if closed { segments } else { segments - 1 };
+        for i in 0..joins {
+            let (prev, pivot) = (points[i], points[i + 1]);
+            // The join at the end of a closed polyline is with its first segment
+            let next = points.get(i + 2).copied().unwrap_or(points[1]);
+            self.join(
+                &mut side,
+                pivot,
+                normal(prev, pivot),
+                normal(pivot, next),
+                tolerance,
+            );
+        }
+        if !closed {
+            side.push(points[segments].add(normal(points[segments - 1], points[segments])));
+        }
+        side
+    }
+    /// Add the points of a join on one side of a stroke
+    ///
+    /// The normals are scaled to half the stroke width.
+    fn join<V>(&self, side: &mut Vec<V>, pivot: V, before: V, after: V, tolerance: S)
+    where
+        V: FloatingVector2<Scalar = S>,
+    {
+        let (start, end) = (pivot.add(before), pivot.add(after));
+        let cross = before.cross(after);
+        if cross.is_zero() && before.dot(after) > S::ZERO {
+            side.push(start);
+            return;
+        }
+        if cross > S::ZERO {
+            // This is the inner side of the turn, so go through the pivot
+            // to keep the stroke's interior filled
+            side.extend([start, pivot, end].iter().copied());
+            return;
+        }
+        match self.join {
+            LineJoin::Bevel => side.extend([start, end].iter().copied()),
+            LineJoin::Round => side.extend(arc_points(
+                pivot,
+                before.mag(),
+                before.atan(),
+                before.angle_between(after),
+                tolerance,
+            )),
+            LineJoin::Miter(limit) => {
+                // The ratio of the miter length to the stroke width is 1 / cos(φ / 2)
+                // where φ is the angle between the normals, and cos²(φ / 2) = (1 + cos φ) / 2
+                let square = before.dot(before);
+                let sum = square + before.dot(after);
+                if limit < S::ONE || sum <= S::ZERO || square * S::TWO > limit * limit * sum {
+                    side.extend([start, end].iter().copied());
+                } else {
+                    // The miter point is on the bisector and on both extended edges
+                    let miter = pivot.add(before.add(after).mul(square / sum));
+                    side.extend([start, miter, end].iter().copied());
+                }
+            }
+            LineJoin::Square => {
+                // The cut is perpendicular to the bisector, so it meets the
+                // extended edges at tan(φ / 4) of the half width from their ends
+                let cos_half = before.add(after).mag() / (before.mag() * S::TWO);
+                let sin_half = (S::ONE - cos_half * cos_half).maxx(S::ZERO).sqrt();
+                let extension = sin_half / (S::ONE + cos_half);
+                side.extend(
+                    [
+                        start.add(before.perp_cw().mul(extension)),
+                        end.sub(after.perp_cw().mul(extension)),
+                    ]
+                    .iter()
+                    .copied(),
+                );
+            }
+        }
+    }
+    /// Add the points of a cap at the end of the segment from `before` to `end`
+    fn cap<V>(&self, outline: &mut Vec<V>, before: V, end: V, tolerance: S)
+    where
+        V: FloatingVector2<Scalar = S>,
+    {
+        let half_width = self.width.abs() / S::TWO;
+        let direction = end.sub(before).unit();
+        let normal = direction.perp().mul(half_width);
+        match self.cap {
+            LineCap::Butt => {}
+            LineCap::Round => {
+                let points = arc_points(end, half_width, normal.atan(), -S::PI, tolerance);
+                outline.extend(points.iter().skip(1).take(points.len().saturating_sub(2)));
+            }
+            LineCap::Square => {
+                let extension = direction.mul(half_width);
+                outline.push(end.add(normal).add(extension));
+                outline.push(end.sub(normal).add(extension));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn stroke() {
+    use crate::{f64, FillRule, Polygon};
+    let contains = |outline: &[Vec<f64::Vec2>], point: f64::Vec2| {
+        let winding: i32 = outline.iter().map(|p| p.winding_number(point)).sum();
+        FillRule::NonZero.is_inside(winding)
+    };
+    let zigzag = [[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [8.0, 4.0]];
+    let miter = Stroke::new(2.0).outline(&zigzag, false, 0.01);
+    assert!(contains(&miter, [4.9, -0.9]));
+    assert!(contains(&miter, [2.0, 0.9]));
+    assert!(!contains(&miter, [-0.1, 0.0]));
+    let bevel = Stroke::new(2.0)
+        .with_join(LineJoin::Bevel)
+        .outline(&zigzag, false, 0.01);
+    assert!(!contains(&bevel, [4.9, -0.9]));
+    assert!(contains(&bevel, [4.4, -0.4]));
+    let round = Stroke::new(2.0)
+        .with_join(LineJoin::Round)
+        .with_cap(LineCap::Round)
+        .outline(&zigzag, false, 0.001);
+    assert!(contains(&round, [4.6, -0.6]));
+    assert!(!contains(&round, [4.8, -0.8]));
+    assert!(contains(&round, [-0.9, 0.0]));
+    // A closed square has a hole
+    let square = [[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]];
+    let ring = Stroke::new(1.0).outline(&square, true, 0.01);
+    assert_eq!(2, ring.len());
+    assert!(contains(&ring, [-0.4, -0.4]));
+    assert!(contains(&ring, [0.4, 2.0]));
+    assert!(!contains(&ring, [2.0, 2.0]));
+    // Dashes follow the pattern across segments and wrap with the offset
+    let dashed = Stroke::new(1.0)
+        .with_dashes(vec![3.0, 2.0], 1.0)
+        .outline(&square, true, 0.01);
+    assert_eq!(4, dashed.len());
+    assert!(contains(&dashed, [1.0, 0.0]));
+    assert!(!contains(&dashed, [3.0, 0.0]));
+    assert!(contains(&dashed, [4.0, 1.0]));
+    assert!(contains(&dashed, [0.0, 1.0]));
+}
@@ -0,0 +1,244 @@
+//! Module for the [`Triangle`] trait
+
+use crate::{Circle, FloatingScalar, FloatingVector2, Scalar as _, Trio, Vector2};
+
+/// The scalar type of a [`Triangle`]
+pub type Scalar<T> = <<T as Triangle>::Vector as Vector2>::Scalar;
+
+/**
+Trait for manipulating triangles
+
+It is implemented for any [`Trio`] of [`FloatingVector2`]s.
+```
+use vector2math::*;
+
+let tri = [[0.0, 0.0], [4.0, 0.0], [0.0, 3.0]];
+assert_eq!(6.0, tri.area());
+assert_eq!(12.0, tri.perimeter());
+assert!(tri.contains([1.0, 1.0]));
+assert_eq!([0.5, 0.25, 0.25], tri.barycentric([1.0, 0.75]));
+let circumcircle: f64::Circ = tri.circumcircle();
+assert_eq!(([2.0, 1.5], 2.5), circumcircle);
+let incircle: f64::Circ = tri.incircle();
+assert_eq!(([1.0, 1.0], 1.0), incircle);
+```
+*/
+pub trait Triangle: Copy
+where
+    Scalar<Self>: FloatingScalar,
+{
+    /// The vector type
+    type Vector: FloatingVector2;
+    /// Create a new triangle from three vertices
+    fn new(a: Self::Vector, b: Self::Vector, c: Self::Vector) -> Self;
+    /// Get the triangle's vertices
+    fn vertices(self) -> [Self::Vector; 3];
+    /// Map this triangle to a triangle of another type
+    fn map_into<T>(self) -> T
+    where
+        T: Triangle,
+        Scalar<T>: FloatingScalar + From<Scalar<Self>>,
+    {
+        let [a, b, c] = self.vertices();
+        T::new(a.map_into(), b.map_into(), c.map_into())
+    }
+    /// Map this triangle to a triangle of another type using a function
+    fn map_with<T, F>(self, mut f: F) -> T
+    where
+        T: Triangle,
+        Scalar<T>: FloatingScalar,
+        F: FnMut(Scalar<Self>) -> <<T as Triangle>::Vector as Vector2>::Scalar,
+    {
+        let [a, b, c] = self.vertices();
+        T::new(a.map_with(&mut f), b.map_with(&mut f), c.map_with(&mut f))
+    }
+    /// Get the triangle that is this one translated by some vector
+    fn translated(self, offset: Self::Vector) -> Self {
+        let [a, b, c] = self.vertices();
+        Self::new(a.add(offset), b.add(offset), c.add(offset))
+    }
+    /// Get the signed area
+    ///
+    /// The area is positive if the vertices are ordered counter-clockwise
+    /// in a coordinate system where the positive Y direction is "up".
+    fn signed_area(self) -> Scalar<Self> {
+        let [a, b, c] = self.vertices();
+        b.sub(a).cross(c.sub(a)) / Scalar::<Self>::TWO
+    }
+    /// Get the area
+    fn area(self) -> Scalar<Self> {
+        self.signed_area().abs()
+    }
+    /// Get the lengths of the sides opposite each vertex
+    fn side_lengths(self) -> [Scalar<Self>; 3] {
+        let [a, b, c] = self.vertices();
+        [b.dist(c), c.dist(a), a.dist(b)]
+    }
+    /// Get the perimeter
+    fn perimeter(self) -> Scalar<Self> {
+        let [a, b, c] = self.side_lengths();
+        a + b + c
+    }
+    /// Get the centroid
+    fn centroid(self) -> Self::Vector {
+        let [a, b, c] = self.vertices();
+        a.add(b)
+            .add(c)
+            .div(Scalar::<Self>::TWO + Scalar::<Self>::ONE)
+    }
+    /// Get the barycentric coordinates of a point relative to the triangle
+    ///
+    /// The coordinates are the weights of each vertex and sum to 1.
+    fn barycentric(self, point: Self::Vector) -> [Scalar<Self>; 3] {
+        let [a, b, c] = self.vertices();
+        point.to_barycentric(a, b, c)
+    }
+    /// Get the point with the given barycentric coordinates relative to the triangle
+    fn point_at(self, weights: [Scalar<Self>; 3]) -> Self::Vector {
+        let [a, b, c] = self.vertices();
+        Self::Vector::from_barycentric(weights, a, b, c)
+    }
+    /// Check that the triangle contains the given point. Includes edges.
+    fn contains(self, point: Self::Vector) -> bool {
+        let [a, b, c] = self.vertices();
+        let zero = Scalar::<Self>::ZERO;
+        let d1 = b.sub(a).cross(point.sub(a));
+        let d2 = c.sub(b).cross(point.sub(b));
+        let d3 = a.sub(c).cross(point.sub(c));
+        let has_neg = d1 < zero || d2 < zero || d3 < zero;
+        let has_pos = d1 > zero || d2 > zero || d3 > zero;
+        !(has_neg && has_pos)
+    }
+    /// Check that the triangle contains all points
+    fn contains_all<I>(self, points: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        points.into_iter().all(|point| self.contains(point))
+    }
+    /// Check that the triangle contains any point
+    fn contains_any<I>(self, points: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Vector>,
+    {
+        points.into_iter().any(|point| self.contains(point))
+    }
+    /// Get the circle that passes through all three vertices
+    ///
+    /// If the triangle is degenerate, the circle is not finite.
+    fn circumcircle<C>(self) -> C
+    where
+        C: Circle<Vector = Self::Vector>,
+    {
+        let [a, b, c] = self.vertices();
+        let ab = b.sub(a);
+        let ac = c.sub(a);
+        let d = Scalar::<Self>::TWO * ab.cross(ac);
+        let offset = Self::Vector::new(
+            ac.y() * ab.squared_mag() - ab.y() * ac.squared_mag(),
+            ab.x() * ac.squared_mag() - ac.x() * ab.squared_mag(),
+        )
+        .div(d);
+        C::new(a.add(offset), offset.mag())
+    }
+    /// Get the largest circle that fits inside the triangle
+    fn incircle<C>(self) -> C
+    where
+        C: Circle<Vector = Self::Vector>,
+    {
+        let [a, b, c] = self.vertices();
+        let [la, lb, lc] = self.side_lengths();
+        let perimeter = la + lb + lc;
+        let center = a.mul(la).add(b.mul(lb)).add(c.mul(lc)).div(perimeter);
+        C::new(center, self.area() * Scalar::<Self>::TWO / perimeter)
+    }
+    /// Get the point where the triangle's three altitudes intersect
+    fn orthocenter(self) -> Self::Vector {
+        let [a, b, c] = self.vertices();
+        let circumcenter = self.circumcircle::<(Self::Vector, Scalar<Self>)>().0;
+        a.add(b).add(c).sub(circumcenter.mul(Scalar::<Self>::TWO))
+    }
+    /// Get the interior angles in radians at each vertex
+    fn angles(self) -> [Scalar<Self>; 3] {
+        let [a, b, c] = self.vertices();
+        let angle = |p: Self::Vector, q: Self::Vector, r: Self::Vector| {
+            q.sub(p).angle_between(r.sub(p)).abs()
+        };
+        [angle(a, b, c), angle(b, c, a), angle(c, a, b)]
+    }
+    /// Get the point in the triangle closest to the given point
+    ///
+    /// If the point is in the triangle, the point itself is returned.
+    fn closest_point(self, point: Self::Vector) -> Self::Vector {
+        let [a, b, c] = self.vertices();
+        let zero = Scalar::<Self>::ZERO;
+        let ab = b.sub(a);
+        let ac = c.sub(a);
+        let ap = point.sub(a);
+        let d1 = ab.dot(ap);
+        let d2 = ac.dot(ap);
+        if d1 <= zero && d2 <= zero {
+            return a;
+        }
+        let bp = point.sub(b);
+        let d3 = ab.dot(bp);
+        let d4 = ac.dot(bp);
+        if d3 >= zero && d4 <= d3 {
+            return b;
+        }
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= zero && d1 >= zero && d3 <= zero {
+            return a.add(ab.mul(d1 / (d1 - d3)));
+        }
+        let cp = point.sub(c);
+        let d5 = ab.dot(cp);
+        let d6 = ac.dot(cp);
+        if d6 >= zero && d5 <= d6 {
+            return c;
+        }
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= zero && d2 >= zero && d6 <= zero {
+            return a.add(ac.mul(d2 / (d2 - d6)));
+        }
+        let va = d3 * d6 - d5 * d4;
+        if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero {
+            return b.add(c.sub(b).mul((d4 - d3) / ((d4 - d3) + (d5 - d6))));
+        }
+        let denom = va + vb + vc;
+        a.add(ab.mul(vb / denom)).add(ac.mul(vc / denom))
+    }
+}
+
+impl<T> Triangle for T
+where
+    T: Trio + Copy,
+    T::Item: FloatingVector2,
+    <T::Item as Vector2>::Scalar: FloatingScalar,
+{
+    type Vector = T::Item;
+    fn new(a: Self::Vector, b: Self::Vector, c: Self::Vector) -> Self {
+        T::from_items(a, b, c)
+    }
+    fn vertices(self) -> [Self::Vector; 3] {
+        let (a, b, c) = self.into_trio();
+        [a, b, c]
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn triangle() {
+    let tri = ([0.0, 0.0], [4.0, 0.0], [1.0, 3.0]);
+    assert_eq!([1.0, 1.0], tri.orthocenter());
+    let [a, b, c] = tri.angles();
+    assert!((a + b + c - f64::PI).abs() < 1e-12);
+    assert!((b - f64::PI / 4.0).abs() < 1e-12);
+    assert_eq!([2.0, 1.0], tri.closest_point([2.0, 1.0]));
+    assert_eq!([2.0, 0.0], tri.closest_point([2.0, -1.0]));
+    assert_eq!([4.0, 0.0], tri.closest_point([5.0, -1.0]));
+    assert_eq!([3.0, 1.0], tri.closest_point([4.0, 2.0]));
+    let clockwise = [[0.0, 0.0], [1.0, 3.0], [4.0, 0.0]];
+    assert_eq!(-6.0, clockwise.signed_area());
+    assert!(clockwise.contains([2.0, 1.0]));
+    assert!(!clockwise.contains([3.0, 2.0]));
+}
@@ -0,0 +1,495 @@
+use std::{cmp::Ordering, collections::HashSet};
+
+use crate::{
+    arc::wrap_angle, hull::robust_orientation, FloatingScalar, FloatingVector2, Polygon,
+    Scalar as _,
+};
+
+/// Triangulate a polygon by ear clipping
+///
+/// `hole_starts` are the indices at which each hole's ring starts.
+/// Holes are connected to the outer ring by bridges before clipping.
+pub(crate) fn ear_clip<V>(points: &[V], hole_starts: &[usize]) -> Vec<[usize; 3]>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let mut starts: Vec<usize> = hole_starts
+        .iter()
+        .copied()
+        .filter(|&start| 0 < start && start < points.len())
+        .collect();
+    starts.sort_unstable();
+    starts.dedup();
+    starts.insert(0, 0);
+    starts.push(points.len());
+    let mut rings = starts.windows(2).map(|w| w[0]..w[1]);
+    let outer = match rings.next() {
+        Some(outer) if outer.len() >= 3 => outer,
+        _ => return Vec::new(),
+    };
+    let flipped = points[outer.clone()].signed_area() < V::Scalar::ZERO;
+    let mut outer: Vec<usize> = outer.collect();
+    if flipped {
+        outer.reverse();
+    }
+    let mut holes: Vec<Vec<usize>> = rings
+        .filter(|ring| ring.len() >= 3)
+        .map(|ring| {
+            let mut hole: Vec<usize> = ring.clone().collect();
+            if points[ring].signed_area() > V::Scalar::ZERO {
+                hole.reverse();
+            }
+            hole
+        })
+        .collect();
+    // Bridge the holes from right to left so that bridges do not cross
+    let max_x = |ring: &Vec<usize>| {
+        ring.iter()
+            .map(|&i| points[i].x())
+            .fold(-V::Scalar::infinity(), |max, x| max.maxx(x))
+    };
+    holes.sort_by(|a, b| max_x(b).partial_cmp(&max_x(a)).unwrap_or(Ordering::Equal));
+    for hole in holes {
+        outer = bridge(points, outer, hole);
+    }
+    let mut triangles = clip_ears(points, &outer);
+    if flipped {
+        for triangle in &mut triangles {
+            triangle.swap(1, 2);
+        }
+    }
+    triangles
+}
+
+/// Triangulate a simple polygon by partitioning it into y-monotone pieces
+pub(crate) fn monotone<V>(points: &[V]) -> Vec<[usize; 3]>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let n = points.len();
+    if n < 3 {
+        return Vec::new();
+    }
+    let all: Vec<usize> = (0..n).collect();
+    let flipped = points.signed_area() < V::Scalar::ZERO;
+    // Work with ring positions of the polygon in counter-clockwise order
+    let ring: Vec<usize> = if flipped {
+        all.into_iter().rev().collect()
+    } else {
+        all
+    };
+    let point = |k: usize| points[ring[k]];
+    let compare = |a: usize, b: usize| sweep_order(point(a), point(b));
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&a, &b| compare(a, b));
+    // Find the diagonals that split the polygon into monotone pieces
+    let mut is_merge = vec![false; n];
+    // Edges from each position to the next, with their helpers
+    let mut active: Vec<(usize, usize)> = Vec::new();
+    let mut diagonals = Vec::new();
+    let left_edge = |active: &[(usize, usize)], v: usize| {
+        let p = point(v);
+        let mut best: Option<(usize, V::Scalar)> = None;
+        for (i, &(edge, _)) in active.iter().enumerate() {
+            let (a, b) = (point(edge), point((edge + 1) % n));
+            let x = if a.y() == b.y() {
+                a.x().maxx(b.x())
+            } else {
+                a.x() + (p.y() - a.y()) * (b.x() - a.x()) / (b.y() - a.y())
+            };
+            let closer = match best {
+                Some((_, best_x)) => x > best_x,
+                None => true,
+            };
+            if x <= p.x() && closer {
+                best = Some((i, x));
+            }
+        }
+        best.map(|(i, _)| i)
+    };
+    for &v in &order {
+        let (prev, next) = ((v + n - 1) % n, (v + 1) % n);
+        let prev_below = compare(v, prev) == Ordering::Less;
+        let next_below = compare(v, next) == Ordering::Less;
+        let convex = robust_orientation(point(prev), point(v), point(next)) != Ordering::Less;
+        let finish_edge = |active: &mut Vec<(usize, usize)>, diagonals: &mut Vec<_>| {
+            if let Some(i) = active.iter().position(|&(edge, _)| edge == prev) {
+                let (_, helper) = active.remove(i);
+                if is_merge[helper] {
+                    diagonals.push((v, helper));
+                }
+            }
+        };
+        if prev_below && next_below {
+            if !convex {
+                // Split vertex
+                if let Some(i) = left_edge(&active, v) {
+                    diagonals.push((v, active[i].1));
+                    active[i].1 = v;
+                }
+            }
+            active.push((v, v));
+        } else if !prev_below && !next_below {
+            finish_edge(&mut active, &mut diagonals);
+            if !convex {
+                // Merge vertex
+                is_merge[v] = true;
+                if let Some(i) = left_edge(&active, v) {
+                    if is_merge[active[i].1] {
+                        diagonals.push((v, active[i].1));
+                    }
+                    active[i].1 = v;
+                }
+            }
+        } else if next_below {
+            // Regular vertex with the interior to its right
+            finish_edge(&mut active, &mut diagonals);
+            active.push((v, v));
+        } else if let Some(i) = left_edge(&active, v) {
+            // Regular vertex with the interior to its left
+            if is_merge[active[i].1] {
+                diagonals.push((v, active[i].1));
+            }
+            active[i].1 = v;
+        }
+    }
+    // Trace the faces formed by the polygon's edges and the diagonals
+    let mut outgoing: Vec<Vec<usize>> = (0..n).map(|k| vec![(k + 1) % n]).collect();
+    for &(a, b) in &diagonals {
+        outgoing[a].push(b);
+        outgoing[b].push(a);
+    }
+    let mut used = HashSet::new();
+    let mut triangles = Vec::with_capacity(n - 2);
+    for start in 0..n {
+        for &first in &outgoing[start] {
+            if used.contains(&(start, first)) {
+                continue;
+            }
+            let mut face = Vec::new();
+            let (mut a, mut b) = (start, first);
+            while used.insert((a, b)) {
+                face.push(a);
+                let back = point(a).sub(point(b)).atan();
+                let turn = |c: usize| {
+                    let turn = wrap_angle(back - point(c).sub(point(b)).atan());
+                    if turn.is_zero() {
+                        V::Scalar::TAU
+                    } else {
+                        turn
+                    }
+                };
+                let c = outgoing[b]
+                    .iter()
+                    .copied()
+                    .min_by(|&c, &d| turn(c).partial_cmp(&turn(d)).unwrap_or(Ordering::Equal))
+                    .unwrap_or(a);
+                a = b;
+                b = c;
+            }
+            triangulate_monotone_face(&point, &face, &mut |[a, b, c]| {
+                triangles.push([ring[a], ring[b], ring[c]])
+            });
+        }
+    }
+    if flipped {
+        for triangle in &mut triangles {
+            triangle.swap(1, 2);
+        }
+    }
+    triangles
+}
+
+/// Compare points in the order of the monotone sweep, from greatest y to least
+fn sweep_order<V>(a: V, b: V) -> Ordering
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    b.y()
+        .partial_cmp(&a.y())
+        .unwrap_or(Ordering::Equal)
+        .then(a.x().partial_cmp(&b.x()).unwrap_or(Ordering::Equal))
+}
+
+/// Check if a point is inside or on the edges of a counter-clockwise triangle
+fn in_triangle<V>(a: V, b: V, c: V, point: V) -> bool
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    robust_orientation(a, b, point) != Ordering::Less
+        && robust_orientation(b, c, point) != Ordering::Less
+        && robust_orientation(c, a, point) != Ordering::Less
+}
+
+/// Connect a clockwise hole to a counter-clockwise ring with a bridge
+fn bridge<V>(points: &[V], ring: Vec<usize>, hole: Vec<usize>) -> Vec<usize>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let n = ring.len();
+    let hole_start = (0..hole.len())
+        .max_by(|&a, &b| {
+            let (a, b) = (points[hole[a]], points[hole[b]]);
+            a.x().partial_cmp(&b.x()).unwrap_or(Ordering::Equal)
+        })
+        .unwrap_or(0);
+    let m = points[hole[hole_start]];
+    // Cast a ray from the hole's rightmost point toward +x and find the closest edge it hits
+    let mut hit: Option<(V::Scalar, usize)> = None;
+    for i in 0..n {
+        let (a, b) = (points[ring[i]], points[ring[(i + 1) % n]]);
+        if a.y() == b.y() || (a.y() < m.y()) == (b.y() < m.y()) && a.y() != m.y() && b.y() != m.y()
+        {
+            continue;
+        }
+        if a.y().minn(b.y()) > m.y() || a.y().maxx(b.y()) < m.y() {
+            continue;
+        }
+        let x = a.x() + (m.y() - a.y()) * (b.x() - a.x()) / (b.y() - a.y());
+        let closer = match hit {
+            Some((hit_x, _)) => x < hit_x,
+            None => true,
+        };
+        if x >= m.x() && closer {
+            hit = Some((x, if a.x() > b.x() { i } else { (i + 1) % n }));
+        }
+    }
+    let (hit_x, mut target) = match hit {
+        Some(hit) => hit,
+        None => return ring,
+    };
+    // A reflex vertex inside the triangle formed by the point, the hit, and the
+    // target would block the bridge, so connect to the best such vertex instead
+    let intersection = V::new(hit_x, m.y());
+    let p = points[ring[target]];
+    let (a, c) = if p.y() < m.y() {
+        (m, intersection)
+    } else {
+        (intersection, m)
+    };
+    let mut best_tan: Option<(V::Scalar, V::Scalar)> = None;
+    for j in 0..n {
+        let r = points[ring[j]];
+        let reflex =
+            robust_orientation(points[ring[(j + n - 1) % n]], r, points[ring[(j + 1) % n]])
+                != Ordering::Greater;
+        if j == target || !reflex || r.x() < m.x() || !in_triangle(a, p, c, r) {
+            continue;
+        }
+        let tan = (r.y() - m.y()).abs() / (r.x() - m.x());
+        let dist = r.squared_dist(m);
+        let better = match best_tan {
+            Some((best, best_dist)) => tan < best || tan == best && dist < best_dist,
+            None => true,
+        };
+        if better {
+            best_tan = Some((tan, dist));
+            target = j;
+        }
+    }
+    let mut bridged = Vec::with_capacity(n + hole.len() + 2);
+    bridged.extend_from_slice(&ring[..=target]);
+    bridged.extend(hole[hole_start..].iter().chain(&hole[..=hole_start]));
+    bridged.extend_from_slice(&ring[target..]);
+    bridged
+}
+
+/// Clip ears off of a counter-clockwise ring until it is a single triangle
+fn clip_ears<V>(points: &[V], ring: &[usize]) -> Vec<[usize; 3]>
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+{
+    let n = ring.len();
+    let mut triangles = Vec::with_capacity(n.saturating_sub(2));
+    if n < 3 {
+        return triangles;
+    }
+    let mut prev: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+    let point = |i: usize| points[ring[i]];
+    let mut remaining = n;
+    let mut i = 0;
+    let mut stalled = 0;
+    while remaining > 3 {
+        let (p, q) = (prev[i], next[i]);
+        let (a, b, c) = (point(p), point(i), point(q));
+        let orientation = robust_orientation(a, b, c);
+        let is_ear = orientation == Ordering::Greater && {
+            let mut j = next[q];
+            let mut blocked = false;
+            while j != p {
+                let r = point(j);
+                let same = |v: V| v.x() == r.x() && v.y() == r.y();
+                if !same(a)
+                    && !same(b)
+                    && !same(c)
+                    && robust_orientation(point(prev[j]), r, point(next[j])) != Ordering::Greater
+                    && in_triangle(a, b, c, r)
+                {
+                    blocked = true;
+                    break;
+                }
+                j = next[j];
+            }
+            !blocked
+        };
+        // Collinear vertices can be removed without adding a triangle, and if the
+        // ring is too degenerate to have any ears, one is clipped anyway
+        if is_ear || orientation == Ordering::Equal || stalled > remaining {
+            if orientation != Ordering::Equal {
+                triangles.push([ring[p], ring[i], ring[q]]);
+            }
+            next[p] = q;
+            prev[q] = p;
+            remaining -= 1;
+            stalled = 0;
+            i = q;
+        } else {
+            stalled += 1;
+            i = q;
+        }
+    }
+    let (p, q) = (prev[i], next[i]);
+    if robust_orientation(point(p), point(i), point(q)) != Ordering::Equal {
+        triangles.push([ring[p], ring[i], ring[q]]);
+    }
+    triangles
+}
+
+/// Triangulate a counter-clockwise y-monotone polygon given as ring positions
+fn triangulate_monotone_face<V, P, F>(point: &P, face: &[usize], emit: &mut F)
+where
+    V: FloatingVector2,
+    V::Scalar: FloatingScalar,
+    P: Fn(usize) -> V,
+    F: FnMut([usize; 3]),
+{
+    let mut add =
+        |a: usize, b: usize, c: usize| match robust_orientation(point(a), point(b), point(c)) {
+            Ordering::Greater => emit([a, b, c]),
+            Ordering::Less => emit([a, c, b]),
+            Ordering::Equal => {}
+        };
+    let m = face.len();
+    if m < 3 {
+        return;
+    }
+    let mut sorted = face.to_vec();
+    sorted.sort_by(|&a, &b| sweep_order(point(a), point(b)));
+    let (top, bottom) = (sorted[0], sorted[m - 1]);
+    // Going counter-clockwise from the top vertex follows the left chain
+    let mut left = HashSet::new();
+    let mut k = face.iter().position(|&v| v == top).unwrap_or(0);
+    while face[k] != bottom {
+        left.insert(face[k]);
+        k = (k + 1) % m;
+    }
+    let mut stack = vec![sorted[0], sorted[1]];
+    for j in 2..m - 1 {
+        let u = sorted[j];
+        let top = stack[stack.len() - 1];
+        if left.contains(&u) != left.contains(&top) {
+            while stack.len() > 1 {
+                let v = stack.pop().unwrap();
+                add(u, v, stack[stack.len() - 1]);
+            }
+            stack.clear();
+            stack.push(sorted[j - 1]);
+        } else {
+            let mut last = stack.pop().unwrap();
+            while let Some(&top) = stack.last() {
+                let turn = robust_orientation(point(top), point(last), point(u));
+                let visible = if left.contains(&u) {
+                    turn == Ordering::Greater
+                } else {
+                    turn == Ordering::Less
+                };
+                if !visible {
+                    break;
+                }
+                add(u, last, top);
+                last = stack.pop().unwrap();
+            }
+            stack.push(last);
+        }
+        stack.push(u);
+    }
+    for pair in stack.windows(2) {
+        add(bottom, pair[0], pair[1]);
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn triangulate() {
+    use crate::{Polygon, Vector2};
+    let area = |points: &[[f64; 2]], triangles: &[[usize; 3]]| {
+        triangles
+            .iter()
+            .map(|&[a, b, c]| points[b].sub(points[a]).cross(points[c].sub(points[a])) / 2.0)
+            .sum::<f64>()
+    };
+    // A comb with several teeth, wound clockwise in Y-up coordinates
+    let comb = [
+        [0.0, 0.0],
+        [0.0, 3.0],
+        [1.0, 1.0],
+        [2.0, 3.0],
+        [3.0, 1.0],
+        [4.0, 3.0],
+        [5.0, 1.0],
+        [6.0, 3.0],
+        [6.0, 0.0],
+    ];
+    // Flipping it upside down turns its merge vertices into split vertices
+    let flipped = comb.map(|[x, y]| [x, -y]);
+    for &comb in [comb, flipped].iter() {
+        let expected = comb.signed_area();
+        let winding = if expected < 0.0 {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+        for triangles in [comb.triangulate(), comb.triangulate_monotone()].iter() {
+            assert_eq!(comb.len() - 2, triangles.len());
+            assert!((area(&comb, triangles) - expected).abs() < 1e-12);
+            for &[a, b, c] in triangles {
+                assert_eq!(winding, robust_orientation(comb[a], comb[b], comb[c]));
+            }
+        }
+    }
+    // A square with two square holes that wind the same way as it does
+    let shape = [
+        [0.0, 0.0],
+        [5.0, 0.0],
+        [5.0, 3.0],
+        [0.0, 3.0],
+        [1.0, 1.0],
+        [2.0, 1.0],
+        [2.0, 2.0],
+        [1.0, 2.0],
+        [3.0, 1.0],
+        [4.0, 1.0],
+        [4.0, 2.0],
+        [3.0, 2.0],
+    ];
+    let triangles = shape.triangulate_with_holes(&[4, 8]);
+    for &[a, b, c] in &triangles {
+        assert_eq!(
+            Ordering::Greater,
+            robust_orientation(shape[a], shape[b], shape[c])
+        );
+    }
+    assert!((area(&shape, &triangles) - 13.0).abs() < 1e-12);
+    // Collinear points are exact
+    assert_eq!(
+        Ordering::Equal,
+        robust_orientation([0.1, 0.1], [0.3, 0.3], [0.7, 0.7])
+    );
+}